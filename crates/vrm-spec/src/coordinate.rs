@@ -0,0 +1,432 @@
+//! Conversion between the model spaces of VRM 0.x and VRM 1.0.
+//!
+//! VRM 0.x models face -Z while VRM 1.0 models face +Z, so the geometry of a model has to be
+//! rotated 180° around the Y axis when it is converted between them. [`migrate`](crate::migrate)
+//! and [`downgrade`](crate::downgrade) already convert vectors of the extensions, such as the
+//! lookAt offset, into the target model space, but they don't touch the glTF. [`rotate_gltf`]
+//! rotates the glTF itself. The other functions rotate the extensions in the same way, to turn a
+//! whole model around without converting its version. As the rotation is its own inverse, the
+//! same functions convert in both directions.
+//!
+//! The rotation is applied to the local space of every node rather than to the root only, so that
+//! nodes keep identity rotations in the rest pose as VRM 1.0 requires. Cameras and lights, which
+//! look at -Z of their nodes, are not adjusted.
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! use vrm_spec::{
+//!     coordinate,
+//!     document::{VrmDocument, VrmExtensions},
+//!     migrate,
+//! };
+//!
+//! let VrmDocument { gltf, extensions } = VrmDocument::from_path("model.vrm").expect("ok");
+//! let VrmExtensions::Vrm0(vrm0) = extensions else {
+//!     return;
+//! };
+//! let (vrmc_vrm, _) = migrate::migrate_vrm0(&vrm0, &gltf.document);
+//!
+//! let mut root = gltf.document.into_json();
+//! let mut buffers = vec![gltf.blob.unwrap_or_default()];
+//! coordinate::rotate_gltf(&mut root, &mut buffers).expect("ok");
+//! // then write `root` with `vrmc_vrm` and `buffers` into a new GLB
+//! ```
+
+use std::{fmt, ops::Neg};
+
+use gltf::json::{
+    accessor::ComponentType, animation::Property, buffer::View, mesh::Semantic, validation::Checked,
+};
+#[cfg(feature = "rustc_hash")]
+use rustc_hash::FxHashMap as HashMap;
+#[cfg(not(feature = "rustc_hash"))]
+use std::collections::HashMap;
+
+use crate::{
+    document::Vrm1Extensions,
+    error::Error,
+    vrm_0_0::{OptionalVector3, VRM0Schema},
+    vrmc_node_constraint_1_0::{AimAxis, VrmcNodeConstraintSchema},
+    vrmc_spring_bone_1_0::VrmcSpringBoneSchema,
+    vrmc_spring_bone_extended_collider_1_0::VRMC_SPRING_BONE_EXTENDED_COLLIDER,
+    vrmc_vrm_1_0::VRMCVrmSchema,
+};
+
+/// An accessor whose data cannot be rotated, e.g. because it is out of its buffer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccessorError {
+    /// JSON pointer to the accessor, e.g. `/accessors/3`
+    pub pointer: String,
+
+    /// Why the data cannot be rotated
+    pub reason: &'static str,
+}
+
+impl fmt::Display for AccessorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "failed to convert '{}': {}", self.pointer, self.reason)
+    }
+}
+
+impl std::error::Error for AccessorError {}
+
+/// Components of a vector which are negated by the rotation
+const VEC3: &[bool] = &[true, false, true];
+
+/// Components of a tangent or a quaternion which are negated by the rotation
+const VEC4: &[bool] = &[true, false, true, false];
+
+/// Components of a column-major matrix which are negated by conjugating it with the rotation
+const MAT4: &[bool] = &[
+    false, true, false, true, //
+    true, false, true, false, //
+    false, true, false, true, //
+    true, false, true, false,
+];
+
+/// Rotates the nodes, meshes, skins and animations of a glTF 180° around the Y axis.
+///
+/// `buffers` are the data of the glTF buffers in order, e.g. the BIN chunk for a GLB. Vertex
+/// positions, normals and tangents including morph targets, inverse bind matrices, and
+/// translation and rotation animations are rewritten in place, and so are `min` and `max` of
+/// their accessors. Each accessor is rewritten once even if it is shared.
+pub fn rotate_gltf(
+    root: &mut gltf::json::Root,
+    buffers: &mut [Vec<u8>],
+) -> Result<(), AccessorError> {
+    for node in &mut root.nodes {
+        if let Some(translation) = &mut node.translation {
+            negate(translation, VEC3);
+        }
+        if let Some(rotation) = &mut node.rotation {
+            negate(&mut rotation.0, VEC4);
+        }
+        if let Some(matrix) = &mut node.matrix {
+            negate(matrix, MAT4);
+        }
+    }
+
+    let mut accessors: HashMap<usize, &[bool]> = HashMap::default();
+    for primitive in root.meshes.iter().flat_map(|mesh| &mesh.primitives) {
+        for (semantic, accessor) in &primitive.attributes {
+            let negated = match semantic {
+                Checked::Valid(Semantic::Positions | Semantic::Normals) => VEC3,
+                Checked::Valid(Semantic::Tangents) => VEC4,
+                _ => continue,
+            };
+            accessors.entry(accessor.value()).or_insert(negated);
+        }
+        for target in primitive.targets.iter().flatten() {
+            // NOTE: tangents of morph targets don't have the handedness
+            for accessor in [target.positions, target.normals, target.tangents]
+                .into_iter()
+                .flatten()
+            {
+                accessors.entry(accessor.value()).or_insert(VEC3);
+            }
+        }
+    }
+    for skin in &root.skins {
+        if let Some(accessor) = skin.inverse_bind_matrices {
+            accessors.entry(accessor.value()).or_insert(MAT4);
+        }
+    }
+    for animation in &root.animations {
+        for channel in &animation.channels {
+            let negated = match channel.target.path {
+                Checked::Valid(Property::Translation) => VEC3,
+                Checked::Valid(Property::Rotation) => VEC4,
+                _ => continue,
+            };
+            if let Some(sampler) = animation.samplers.get(channel.sampler.value()) {
+                accessors.entry(sampler.output.value()).or_insert(negated);
+            }
+        }
+    }
+
+    let mut accessors: Vec<_> = accessors.into_iter().collect();
+    accessors.sort_unstable_by_key(|&(index, _)| index);
+    for (index, negated) in accessors {
+        negate_accessor(root, buffers, index, negated).map_err(|reason| AccessorError {
+            pointer: format!("/accessors/{}", index),
+            reason,
+        })?;
+    }
+    Ok(())
+}
+
+/// Rotates the vectors of the `VRM` extension of VRM 0.x 180° around the Y axis.
+pub fn rotate_vrm0(vrm0: &mut VRM0Schema) {
+    if let Some(offset) = vrm0
+        .first_person
+        .as_mut()
+        .and_then(|first_person| first_person.first_person_bone_offset.as_mut())
+    {
+        rotate_optional_vector3(offset);
+    }
+    if let Some(secondary_animation) = &mut vrm0.secondary_animation {
+        for group in secondary_animation.collider_groups.iter_mut().flatten() {
+            for collider in group.colliders.iter_mut().flatten() {
+                if let Some(offset) = &mut collider.offset {
+                    rotate_optional_vector3(offset);
+                }
+            }
+        }
+        for spring in secondary_animation.bone_groups.iter_mut().flatten() {
+            if let Some(gravity_dir) = &mut spring.gravity_dir {
+                rotate_optional_vector3(gravity_dir);
+            }
+        }
+    }
+}
+
+/// Rotates the vectors of the `VRMC_vrm` extension 180° around the Y axis.
+pub fn rotate_vrmc_vrm(vrmc_vrm: &mut VRMCVrmSchema) {
+    if let Some(offset) = vrmc_vrm
+        .look_at
+        .as_mut()
+        .and_then(|look_at| look_at.offset_from_head_bone.as_mut())
+    {
+        negate(offset, VEC3);
+    }
+}
+
+/// Rotates the vectors of the `VRMC_springBone` extension 180° around the Y axis, including the
+/// shapes of `VRMC_springBone_extended_collider`.
+pub fn rotate_spring_bone(spring_bone: &mut VrmcSpringBoneSchema) -> Result<(), Error> {
+    for collider in spring_bone.colliders.iter_mut().flatten() {
+        if let Some(sphere) = &mut collider.shape.sphere {
+            rotate_vector3(&mut sphere.offset);
+        }
+        if let Some(capsule) = &mut collider.shape.capsule {
+            rotate_vector3(&mut capsule.offset);
+            rotate_vector3(&mut capsule.tail);
+        }
+
+        let Some(mut extended_collider) = collider.extended_collider().transpose()? else {
+            continue;
+        };
+        let shape = &mut extended_collider.shape;
+        if let Some(sphere) = &mut shape.sphere {
+            rotate_vector3(&mut sphere.offset);
+        }
+        if let Some(capsule) = &mut shape.capsule {
+            rotate_vector3(&mut capsule.offset);
+            rotate_vector3(&mut capsule.tail);
+        }
+        if let Some(plane) = &mut shape.plane {
+            rotate_vector3(&mut plane.offset);
+            rotate_vector3(&mut plane.normal);
+        }
+        let value = serde_json::to_value(&extended_collider).map_err(Error::Serialize)?;
+        collider
+            .extensions
+            .get_or_insert_with(Default::default)
+            .insert(
+                VRMC_SPRING_BONE_EXTENDED_COLLIDER.to_owned(),
+                serde_json::from_value(value).map_err(Error::Serialize)?,
+            );
+    }
+    for joint in spring_bone
+        .springs
+        .iter_mut()
+        .flatten()
+        .flat_map(|spring| &mut spring.joints)
+    {
+        rotate_vector3(&mut joint.gravity_dir);
+    }
+    Ok(())
+}
+
+/// Rotates the axes of the `VRMC_node_constraint` extension 180° around the Y axis.
+pub fn rotate_node_constraint(node_constraint: &mut VrmcNodeConstraintSchema) {
+    // NOTE: roll and rotation constraints are invariant as their sources and destinations are
+    // rotated together
+    if let Some(aim) = &mut node_constraint.constraint.aim {
+        aim.aim_axis = match aim.aim_axis {
+            AimAxis::PositiveX => AimAxis::NegativeX,
+            AimAxis::NegativeX => AimAxis::PositiveX,
+            AimAxis::PositiveZ => AimAxis::NegativeZ,
+            AimAxis::NegativeZ => AimAxis::PositiveZ,
+            axis => axis,
+        };
+    }
+}
+
+/// Rotates the vectors of all the VRM 1.0 extensions 180° around the Y axis.
+pub fn rotate_vrm1(vrm1: &mut Vrm1Extensions) -> Result<(), Error> {
+    rotate_vrmc_vrm(&mut vrm1.vrmc_vrm);
+    if let Some(spring_bone) = &mut vrm1.vrmc_spring_bone {
+        rotate_spring_bone(spring_bone)?;
+    }
+    for node_constraint in vrm1.vrmc_node_constraints.iter_mut().flatten() {
+        rotate_node_constraint(node_constraint);
+    }
+    Ok(())
+}
+
+fn negate<T>(values: &mut [T], negated: &[bool])
+where
+    T: Copy + Neg<Output = T>,
+{
+    for (value, &negated) in values.iter_mut().zip(negated) {
+        if negated {
+            *value = -*value;
+        }
+    }
+}
+
+fn rotate_vector3(vector: &mut Option<[f64; 3]>) {
+    if let Some(vector) = vector {
+        negate(vector, VEC3);
+    }
+}
+
+fn rotate_optional_vector3(vector: &mut OptionalVector3) {
+    vector.x = vector.x.map(|x| -x);
+    vector.z = vector.z.map(|z| -z);
+}
+
+fn negate_accessor(
+    root: &mut gltf::json::Root,
+    buffers: &mut [Vec<u8>],
+    index: usize,
+    negated: &[bool],
+) -> Result<(), &'static str> {
+    let accessor = root
+        .accessors
+        .get_mut(index)
+        .ok_or("the accessor does not exist")?;
+    let Checked::Valid(component_type) = accessor.component_type else {
+        return Err("the component type is invalid");
+    };
+    let component_type = component_type.0;
+    if !matches!(
+        component_type,
+        ComponentType::F32 | ComponentType::I8 | ComponentType::I16
+    ) {
+        return Err("unsigned components cannot be negated");
+    }
+    let Checked::Valid(type_) = accessor.type_ else {
+        return Err("the type is invalid");
+    };
+    if type_.multiplicity() != negated.len() {
+        return Err("the type is unexpected for the attribute");
+    }
+
+    if let Some(view) = accessor.buffer_view {
+        let offset = accessor.byte_offset.map_or(0, |offset| offset.0 as usize);
+        negate_elements(
+            &root.buffer_views,
+            buffers,
+            view.value(),
+            offset,
+            accessor.count.0 as usize,
+            component_type,
+            negated,
+            true,
+        )?;
+    }
+    if let Some(sparse) = &accessor.sparse {
+        negate_elements(
+            &root.buffer_views,
+            buffers,
+            sparse.values.buffer_view.value(),
+            sparse.values.byte_offset.0 as usize,
+            sparse.count.0 as usize,
+            component_type,
+            negated,
+            false,
+        )?;
+    }
+
+    if let (Some(serde_json::Value::Array(min)), Some(serde_json::Value::Array(max))) =
+        (&mut accessor.min, &mut accessor.max)
+    {
+        for ((min, max), &negated) in min.iter_mut().zip(max.iter_mut()).zip(negated) {
+            if negated {
+                let (negated_min, negated_max) = (negate_number(max), negate_number(min));
+                *min = negated_min;
+                *max = negated_max;
+            }
+        }
+    }
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+fn negate_elements(
+    views: &[View],
+    buffers: &mut [Vec<u8>],
+    view: usize,
+    offset: usize,
+    count: usize,
+    component_type: ComponentType,
+    negated: &[bool],
+    strided: bool,
+) -> Result<(), &'static str> {
+    let view = views.get(view).ok_or("the buffer view does not exist")?;
+    let buffer = buffers
+        .get_mut(view.buffer.value())
+        .ok_or("the buffer is not given")?;
+
+    let component_size = component_type.size();
+    let element_size = component_size * negated.len();
+    // NOTE: sparse values are always tightly packed
+    let stride = match view.byte_stride {
+        Some(stride) if strided => stride.0,
+        _ => element_size,
+    };
+    let view_offset = view.byte_offset.map_or(0, |offset| offset.0 as usize);
+    let view_end = view_offset.saturating_add(view.byte_length.0 as usize);
+    let start = view_offset.saturating_add(offset);
+    if count > 0 {
+        let end = stride
+            .checked_mul(count - 1)
+            .and_then(|size| size.checked_add(start))
+            .and_then(|size| size.checked_add(element_size));
+        if end.map_or(true, |end| end > view_end) || view_end > buffer.len() {
+            return Err("the data is out of the buffer");
+        }
+    }
+
+    for i in 0..count {
+        let element = start + i * stride;
+        for (j, _) in negated.iter().enumerate().filter(|(_, &negated)| negated) {
+            let component = element + j * component_size;
+            negate_component(
+                &mut buffer[component..component + component_size],
+                component_type,
+            );
+        }
+    }
+    Ok(())
+}
+
+fn negate_component(bytes: &mut [u8], component_type: ComponentType) {
+    match component_type {
+        ComponentType::F32 => {
+            let value = f32::from_le_bytes(bytes.try_into().expect("4 bytes"));
+            bytes.copy_from_slice(&(-value).to_le_bytes());
+        }
+        // NOTE: saturate -128 and -32768, which are -1.0 as well as the next values when normalized
+        ComponentType::I8 => bytes[0] = (bytes[0] as i8).saturating_neg() as u8,
+        ComponentType::I16 => {
+            let value = i16::from_le_bytes(bytes.try_into().expect("2 bytes"));
+            bytes.copy_from_slice(&value.saturating_neg().to_le_bytes());
+        }
+        ComponentType::U8 | ComponentType::U16 | ComponentType::U32 => {
+            unreachable!("unsigned components are rejected")
+        }
+    }
+}
+
+fn negate_number(value: &serde_json::Value) -> serde_json::Value {
+    match value.as_i64() {
+        Some(value) => (-value).into(),
+        None => value
+            .as_f64()
+            .map_or_else(|| value.clone(), |value| (-value).into()),
+    }
+}
@@ -0,0 +1,143 @@
+//! Loader of VRM files which detects VRM 0.x and VRM 1.0 automatically.
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! use vrm_spec::document::{VrmDocument, VrmExtensions};
+//!
+//! let vrm = VrmDocument::from_path("model.vrm").expect("ok");
+//! match vrm.extensions {
+//!     VrmExtensions::Vrm0(vrm0) => println!("VRM 0.x: {:?}", vrm0.meta),
+//!     VrmExtensions::Vrm1(vrm1) => println!("VRM 1.0: {}", vrm1.vrmc_vrm.meta.name),
+//! }
+//! ```
+
+use std::{io, path::Path};
+
+use crate::{
+    error::{from_value_at, Error},
+    vrm_0_0::{VRM0Schema, VRM},
+    vrmc_materials_mtoon_1_0::{VrmcMaterialsMtoonSchema, VRMC_MATERIALS_MTOON},
+    vrmc_node_constraint_1_0::{VrmcNodeConstraintSchema, VRMC_NODE_CONSTRAINT},
+    vrmc_spring_bone_1_0::{VrmcSpringBoneSchema, VRMC_SPRING_BONE},
+    vrmc_vrm_1_0::{VRMCVrmSchema, VRMC_VRM},
+};
+
+/// A glTF document with its VRM extensions.
+#[derive(Debug)]
+pub struct VrmDocument {
+    /// The glTF document and the BIN chunk of the GLB
+    pub gltf: gltf::Gltf,
+
+    /// VRM extensions of the document
+    pub extensions: VrmExtensions,
+}
+
+/// VRM extensions of either VRM 0.x or VRM 1.0
+#[derive(Debug, Clone)]
+pub enum VrmExtensions {
+    /// The `VRM` extension of VRM 0.x
+    Vrm0(Box<VRM0Schema>),
+    /// The `VRMC_*` extensions of VRM 1.0
+    Vrm1(Box<Vrm1Extensions>),
+}
+
+/// The `VRMC_*` extensions of VRM 1.0
+#[derive(Debug, Clone)]
+pub struct Vrm1Extensions {
+    /// The `VRMC_vrm` extension of the document
+    pub vrmc_vrm: VRMCVrmSchema,
+
+    /// The `VRMC_springBone` extension of the document
+    pub vrmc_spring_bone: Option<VrmcSpringBoneSchema>,
+
+    /// The `VRMC_materials_mtoon` extension of each material, in the order of glTF materials
+    pub vrmc_materials_mtoon: Vec<Option<VrmcMaterialsMtoonSchema>>,
+
+    /// The `VRMC_node_constraint` extension of each node, in the order of glTF nodes
+    pub vrmc_node_constraints: Vec<Option<VrmcNodeConstraintSchema>>,
+}
+
+impl VrmDocument {
+    /// Loads a VRM from a slice of GLB bytes.
+    pub fn from_slice(slice: &[u8]) -> Result<Self, Error> {
+        Self::from_gltf(gltf::Gltf::from_slice(slice)?)
+    }
+
+    /// Loads a VRM from a reader of GLB bytes.
+    pub fn from_reader<R>(reader: R) -> Result<Self, Error>
+    where
+        R: io::Read + io::Seek,
+    {
+        Self::from_gltf(gltf::Gltf::from_reader(reader)?)
+    }
+
+    /// Loads a VRM from a file.
+    pub fn from_path<P>(path: P) -> Result<Self, Error>
+    where
+        P: AsRef<Path>,
+    {
+        Self::from_gltf(gltf::Gltf::open(path)?)
+    }
+
+    /// Reads VRM extensions from a loaded glTF. `VRMC_vrm` takes precedence over `VRM` when a
+    /// document has both.
+    pub fn from_gltf(gltf: gltf::Gltf) -> Result<Self, Error> {
+        let extensions = if let Some(value) = gltf.document.extension_value(VRMC_VRM) {
+            VrmExtensions::Vrm1(Box::new(Vrm1Extensions {
+                vrmc_vrm: from_value_at(value, &format!("/extensions/{}", VRMC_VRM))?,
+                vrmc_spring_bone: gltf
+                    .document
+                    .extension_value(VRMC_SPRING_BONE)
+                    .map(|value| from_value_at(value, &format!("/extensions/{}", VRMC_SPRING_BONE)))
+                    .transpose()?,
+                vrmc_materials_mtoon: gltf
+                    .document
+                    .materials()
+                    .enumerate()
+                    .map(|(i, material)| {
+                        material
+                            .extension_value(VRMC_MATERIALS_MTOON)
+                            .map(|value| {
+                                from_value_at(
+                                    value,
+                                    &format!(
+                                        "/materials/{}/extensions/{}",
+                                        i, VRMC_MATERIALS_MTOON
+                                    ),
+                                )
+                            })
+                            .transpose()
+                    })
+                    .collect::<Result<_, _>>()?,
+                vrmc_node_constraints: gltf
+                    .document
+                    .nodes()
+                    .map(|node| {
+                        node.extension_value(VRMC_NODE_CONSTRAINT)
+                            .map(|value| {
+                                from_value_at(
+                                    value,
+                                    &format!(
+                                        "/nodes/{}/extensions/{}",
+                                        node.index(),
+                                        VRMC_NODE_CONSTRAINT
+                                    ),
+                                )
+                            })
+                            .transpose()
+                    })
+                    .collect::<Result<_, _>>()?,
+            }))
+        } else if let Some(value) = gltf.document.extension_value(VRM) {
+            VrmExtensions::Vrm0(Box::new(from_value_at(
+                value,
+                &format!("/extensions/{}", VRM),
+            )?))
+        } else {
+            return Err(Error::MissingExtension(&[VRMC_VRM, VRM]));
+        };
+
+        Ok(Self { gltf, extensions })
+    }
+}
@@ -0,0 +1,161 @@
+//! Down-conversion of VRM 1.0 data structures to VRM 0.x, for clients which only support VRM 0.x.
+//!
+//! This is the reverse of [`migrate`](crate::migrate). VRM 1.0 features which VRM 0.x cannot
+//! represent are dropped or approximated, and each of them is recorded in a [`DowngradeReport`] so
+//! that users can be warned before exporting.
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! use vrm_spec::{
+//!     document::{VrmDocument, VrmExtensions},
+//!     downgrade,
+//! };
+//!
+//! let vrm = VrmDocument::from_path("model.vrm").expect("ok");
+//! if let VrmExtensions::Vrm1(vrm1) = &vrm.extensions {
+//!     let (vrm0, report) = downgrade::downgrade_vrm1(vrm1, &vrm.gltf.document);
+//!     for unsupported in report.unsupported {
+//!         println!("{:?} at {}: {}", unsupported.feature, unsupported.pointer, unsupported.message);
+//!     }
+//! }
+//! ```
+
+mod vrmc_materials_mtoon;
+mod vrmc_spring_bone;
+mod vrmc_vrm;
+
+pub use vrmc_materials_mtoon::downgrade_materials;
+pub use vrmc_spring_bone::downgrade_spring_bone;
+pub use vrmc_vrm::downgrade_vrmc_vrm;
+
+use serde::Serialize;
+
+use crate::{
+    document::Vrm1Extensions, vrm_0_0::VRM0Schema, vrmc_node_constraint_1_0::VRMC_NODE_CONSTRAINT,
+};
+
+/// Features of VRM 1.0 which cannot be represented in VRM 0.x
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct DowngradeReport {
+    pub unsupported: Vec<Unsupported>,
+}
+
+/// A feature of VRM 1.0 which cannot be represented in VRM 0.x
+#[derive(Debug, Clone, Serialize)]
+pub struct Unsupported {
+    /// Kind of the feature
+    pub feature: UnsupportedFeature,
+
+    /// JSON pointer to the feature in the glTF JSON, e.g.
+    /// `/extensions/VRMC_springBone/colliders/3/shape/capsule`
+    pub pointer: String,
+
+    /// Description of how the feature is handled
+    pub message: String,
+}
+
+/// Kind of a feature of VRM 1.0 which cannot be represented in VRM 0.x
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum UnsupportedFeature {
+    /// Capsule colliders of `VRMC_springBone`
+    CapsuleCollider,
+
+    /// Colliders of `VRMC_springBone_extended_collider`
+    ExtendedCollider,
+
+    /// Joints of a spring which have different parameters
+    SpringJointParameters,
+
+    /// Springs which don't cover all the descendants of the root joint
+    SpringChain,
+
+    /// Texture transform binds of expressions
+    TextureTransformBind,
+
+    /// Material color binds which have no MToon 0.x property
+    MaterialColorBind,
+
+    /// `overrideBlink`, `overrideLookAt` and `overrideMouth` of expressions
+    ExpressionOverride,
+
+    /// Preset expressions which have no VRM 0.x preset
+    ExpressionPreset,
+
+    /// `VRMC_node_constraint`
+    NodeConstraint,
+
+    /// Usage permissions which are not defined in VRM 0.x
+    MetaPermission,
+
+    /// Meta information which is not defined in VRM 0.x
+    MetaField,
+
+    /// Thumbnail images which are not referred by any texture
+    ThumbnailImage,
+
+    /// MToon 1.0 properties which have no MToon 0.x counterpart
+    MToonProperty,
+
+    /// References to glTF objects which don't exist or cannot be converted
+    UnresolvedReference,
+
+    /// Collider shapes which have none or both of `sphere` and `capsule`
+    InvalidColliderShape,
+}
+
+impl DowngradeReport {
+    pub(crate) fn push(
+        &mut self,
+        feature: UnsupportedFeature,
+        pointer: impl Into<String>,
+        message: impl Into<String>,
+    ) {
+        self.unsupported.push(Unsupported {
+            feature,
+            pointer: pointer.into(),
+            message: message.into(),
+        });
+    }
+}
+
+/// Converts all the VRM 1.0 extensions to the `VRM` extension of VRM 0.x.
+pub fn downgrade_vrm1(
+    vrm1: &Vrm1Extensions,
+    doc: &gltf::Document,
+) -> (VRM0Schema, DowngradeReport) {
+    let mut report = DowngradeReport::default();
+
+    let mut vrm0 = downgrade_vrmc_vrm(&vrm1.vrmc_vrm, doc, &mut report);
+    vrm0.secondary_animation = vrm1
+        .vrmc_spring_bone
+        .as_ref()
+        .map(|spring_bone| downgrade_spring_bone(spring_bone, doc, &mut report));
+    vrm0.material_properties = Some(downgrade_materials(
+        &vrm1.vrmc_materials_mtoon,
+        doc,
+        &mut report,
+    ));
+
+    for (i, constraint) in vrm1.vrmc_node_constraints.iter().enumerate() {
+        if constraint.is_some() {
+            report.push(
+                UnsupportedFeature::NodeConstraint,
+                format!("/nodes/{}/extensions/{}", i, VRMC_NODE_CONSTRAINT),
+                "node constraints are not supported in VRM 0.x and dropped",
+            );
+        }
+    }
+
+    (vrm0, report)
+}
+
+/// Converts a color component from linear to gamma (sRGB)
+pub(crate) fn linear_to_gamma(c: f64) -> f64 {
+    if c <= 0.0031308 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    }
+}
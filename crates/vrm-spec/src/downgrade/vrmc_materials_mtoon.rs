@@ -0,0 +1,290 @@
+use gltf::json::{material::AlphaMode, texture::Info as TextureInfo, validation::Checked, Index};
+#[cfg(feature = "rustc_hash")]
+use rustc_hash::FxHashMap as HashMap;
+#[cfg(not(feature = "rustc_hash"))]
+use std::collections::HashMap;
+
+use super::{linear_to_gamma, DowngradeReport, UnsupportedFeature};
+use crate::{
+    migrate::{GLTF_SHADER, MTOON_SHADER},
+    vrm_0_0::VRMMaterial,
+    vrmc_materials_hdr_emissive_multiplier_1_0::effective_emissive_factor,
+    vrmc_materials_mtoon_1_0::{OutlineWidthMode, VrmcMaterialsMtoonSchema, VRMC_MATERIALS_MTOON},
+};
+
+/// Converts the `VRMC_materials_mtoon` extension of each material to `materialProperties` of the
+/// `VRM` extension of VRM 0.x.
+///
+/// `vrmc_materials_mtoon` is in the order of glTF materials, and so is the returned `Vec`.
+/// Materials without `VRMC_materials_mtoon` use the glTF material as is.
+pub fn downgrade_materials(
+    vrmc_materials_mtoon: &[Option<VrmcMaterialsMtoonSchema>],
+    doc: &gltf::Document,
+    report: &mut DowngradeReport,
+) -> Vec<VRMMaterial> {
+    doc.as_json()
+        .materials
+        .iter()
+        .enumerate()
+        .map(
+            |(i, material)| match vrmc_materials_mtoon.get(i).and_then(Option::as_ref) {
+                Some(mtoon) => {
+                    let pointer = format!("/materials/{}/extensions/{}", i, VRMC_MATERIALS_MTOON);
+                    downgrade_mtoon(material, mtoon, &pointer, report)
+                }
+                None => VRMMaterial {
+                    name: material.name.clone(),
+                    shader: Some(GLTF_SHADER.to_owned()),
+                    ..Default::default()
+                },
+            },
+        )
+        .collect()
+}
+
+fn downgrade_mtoon(
+    material: &gltf::json::Material,
+    mtoon: &VrmcMaterialsMtoonSchema,
+    pointer: &str,
+    report: &mut DowngradeReport,
+) -> VRMMaterial {
+    if mtoon.shading_shift_texture.is_some() {
+        report.push(
+            UnsupportedFeature::MToonProperty,
+            format!("{}/shadingShiftTexture", pointer),
+            "shadingShiftTexture is not supported in VRM 0.x and dropped",
+        );
+    }
+    if mtoon.matcap_texture.is_some() && mtoon.matcap_factor.map_or(false, |f| f != [1.0; 3]) {
+        report.push(
+            UnsupportedFeature::MToonProperty,
+            format!("{}/matcapFactor", pointer),
+            "matcapFactor is not supported in VRM 0.x; the matcap is added as is",
+        );
+    }
+
+    let mut float_properties: HashMap<&str, f64> = HashMap::default();
+    let mut vector_properties: HashMap<&str, Vec<f64>> = HashMap::default();
+    let mut texture_properties = HashMap::default();
+    let mut keyword_map = HashMap::default();
+    let mut tag_map = HashMap::default();
+
+    let mut texture =
+        |name: &str, property: &str, info: Option<(Index<gltf::json::Texture>, u32)>| {
+            let Some((index, tex_coord)) = info else {
+                return;
+            };
+            if tex_coord != 0 {
+                report.push(
+                    UnsupportedFeature::MToonProperty,
+                    format!("{}/{}/texCoord", pointer, name),
+                    "VRM 0.x only uses TEXCOORD_0",
+                );
+            }
+            texture_properties.insert(property.to_owned(), index);
+        };
+    let info = |info: &Option<TextureInfo>| info.as_ref().map(|info| (info.index, info.tex_coord));
+    // NOTE: colors other than emission are in gamma space in Unity
+    let gamma_color = |[r, g, b]: [f64; 3], a: f64| {
+        vec![
+            linear_to_gamma(r),
+            linear_to_gamma(g),
+            linear_to_gamma(b),
+            a,
+        ]
+    };
+
+    // rendering
+    let (blend_mode, render_queue, render_type) = match material.alpha_mode {
+        Checked::Valid(AlphaMode::Mask) => (1, 2450, "TransparentCutout"),
+        Checked::Valid(AlphaMode::Blend) if mtoon.transparent_with_z_write == Some(true) => {
+            let offset = mtoon.render_queue_offset_number.unwrap_or_default();
+            (3, 2501 + offset.clamp(0, 9), "Transparent")
+        }
+        Checked::Valid(AlphaMode::Blend) => {
+            let offset = mtoon.render_queue_offset_number.unwrap_or_default();
+            (2, 3000 + offset.clamp(-9, 0), "Transparent")
+        }
+        _ => (0, 2000, "Opaque"),
+    };
+    float_properties.insert("_BlendMode", blend_mode as f64);
+    float_properties.insert(
+        "_Cutoff",
+        material.alpha_cutoff.map_or(0.5, |cutoff| cutoff.0 as f64),
+    );
+    float_properties.insert("_CullMode", if material.double_sided { 0.0 } else { 2.0 });
+    tag_map.insert("RenderType".to_owned(), render_type.to_owned());
+    match blend_mode {
+        1 => keyword_map.insert("_ALPHATEST_ON".to_owned(), true),
+        2 | 3 => keyword_map.insert("_ALPHABLEND_ON".to_owned(), true),
+        _ => None,
+    };
+
+    // lighting
+    let [r, g, b, a] = material.pbr_metallic_roughness.base_color_factor.0;
+    vector_properties.insert(
+        "_Color",
+        gamma_color([r as f64, g as f64, b as f64], a as f64),
+    );
+    texture(
+        "pbrMetallicRoughness/baseColorTexture",
+        "_MainTex",
+        info(&material.pbr_metallic_roughness.base_color_texture),
+    );
+    vector_properties.insert("_MainTex_ST", vec![1.0, 1.0, 0.0, 0.0]);
+    vector_properties.insert(
+        "_ShadeColor",
+        gamma_color(mtoon.shade_color_factor.unwrap_or([0.0, 0.0, 0.0]), 1.0),
+    );
+    texture(
+        "shadeMultiplyTexture",
+        "_ShadeTexture",
+        info(&mtoon.shade_multiply_texture),
+    );
+    if let Some(normal) = &material.normal_texture {
+        texture(
+            "normalTexture",
+            "_BumpMap",
+            Some((normal.index, normal.tex_coord)),
+        );
+        float_properties.insert("_BumpScale", normal.scale as f64);
+        keyword_map.insert("_NORMALMAP".to_owned(), true);
+    }
+    let (shade_toony, shade_shift) = downgrade_shading(
+        mtoon.shading_toony_factor.unwrap_or(0.9),
+        mtoon.shading_shift_factor.unwrap_or(0.0),
+    );
+    float_properties.insert("_ShadeToony", shade_toony);
+    float_properties.insert("_ShadeShift", shade_shift);
+
+    // global illumination
+    float_properties.insert(
+        "_IndirectLightIntensity",
+        1.0 - mtoon.gi_equalization_factor.unwrap_or(0.9),
+    );
+
+    // emission
+    let [r, g, b] = effective_emissive_factor(material).unwrap_or(material.emissive_factor.0);
+    vector_properties.insert("_EmissionColor", vec![r as f64, g as f64, b as f64, 1.0]);
+    texture(
+        "emissiveTexture",
+        "_EmissionMap",
+        info(&material.emissive_texture),
+    );
+
+    // rim lighting
+    texture("matcapTexture", "_SphereAdd", info(&mtoon.matcap_texture));
+    vector_properties.insert(
+        "_RimColor",
+        gamma_color(
+            mtoon.parametric_rim_color_factor.unwrap_or([0.0, 0.0, 0.0]),
+            1.0,
+        ),
+    );
+    float_properties.insert(
+        "_RimFresnelPower",
+        mtoon.parametric_rim_fresnel_power_factor.unwrap_or(5.0),
+    );
+    float_properties.insert("_RimLift", mtoon.parametric_rim_lift_factor.unwrap_or(0.0));
+    float_properties.insert(
+        "_RimLightingMix",
+        mtoon.rim_lighting_mix_factor.unwrap_or(1.0),
+    );
+    texture(
+        "rimMultiplyTexture",
+        "_RimTexture",
+        info(&mtoon.rim_multiply_texture),
+    );
+
+    // outline
+    let (outline_width_mode, keyword) = match mtoon.outline_width_mode {
+        Some(OutlineWidthMode::WorldCoordinates) => (1.0, Some("MTOON_OUTLINE_WIDTH_WORLD")),
+        Some(OutlineWidthMode::ScreenCoordinates) => (2.0, Some("MTOON_OUTLINE_WIDTH_SCREEN")),
+        Some(OutlineWidthMode::None) | None => (0.0, None),
+    };
+    float_properties.insert("_OutlineWidthMode", outline_width_mode);
+    // NOTE: the width is in centimeters in VRM 0.x
+    float_properties.insert(
+        "_OutlineWidth",
+        mtoon.outline_width_factor.unwrap_or(0.0) * 100.0,
+    );
+    texture(
+        "outlineWidthMultiplyTexture",
+        "_OutlineWidthTexture",
+        info(&mtoon.outline_width_multiply_texture),
+    );
+    vector_properties.insert(
+        "_OutlineColor",
+        gamma_color(mtoon.outline_color_factor.unwrap_or([0.0, 0.0, 0.0]), 1.0),
+    );
+    let outline_lighting_mix = mtoon.outline_lighting_mix_factor.unwrap_or(1.0);
+    let outline_color_mode = if outline_lighting_mix > 0.0 { 1.0 } else { 0.0 };
+    float_properties.insert("_OutlineColorMode", outline_color_mode);
+    float_properties.insert("_OutlineLightingMix", outline_lighting_mix);
+    if let Some(keyword) = keyword {
+        keyword_map.insert(keyword.to_owned(), true);
+        let keyword = if outline_color_mode > 0.0 {
+            "MTOON_OUTLINE_COLOR_MIXED"
+        } else {
+            "MTOON_OUTLINE_COLOR_FIXED"
+        };
+        keyword_map.insert(keyword.to_owned(), true);
+    }
+
+    // UV animation
+    texture(
+        "uvAnimationMaskTexture",
+        "_UvAnimMaskTexture",
+        info(&mtoon.uv_animation_mask_texture),
+    );
+    float_properties.insert(
+        "_UvAnimRotation",
+        mtoon.uv_animation_rotation_speed_factor.unwrap_or(0.0),
+    );
+    float_properties.insert(
+        "_UvAnimScrollX",
+        mtoon.uv_animation_scroll_x_speed_factor.unwrap_or(0.0),
+    );
+    // NOTE: the origin of UV is bottom-left in Unity and top-left in glTF
+    float_properties.insert(
+        "_UvAnimScrollY",
+        -mtoon.uv_animation_scroll_y_speed_factor.unwrap_or(0.0),
+    );
+
+    VRMMaterial {
+        float_properties: Some(
+            float_properties
+                .into_iter()
+                .map(|(name, value)| (name.to_owned(), value))
+                .collect(),
+        ),
+        keyword_map: Some(keyword_map),
+        name: material.name.clone(),
+        render_queue: Some(render_queue),
+        shader: Some(MTOON_SHADER.to_owned()),
+        tag_map: Some(tag_map),
+        texture_properties: Some(texture_properties),
+        vector_properties: Some(
+            vector_properties
+                .into_iter()
+                .map(|(name, value)| (name.to_owned(), value))
+                .collect(),
+        ),
+        #[cfg(feature = "preserve_unknown_fields")]
+        unknown_fields: Default::default(),
+    }
+}
+
+/// Converts `shadingToonyFactor` and `shadingShiftFactor` of VRM 1.0 to `_ShadeToony` and
+/// `_ShadeShift` of VRM 0.x. This is the inverse of the migration.
+fn downgrade_shading(shading_toony: f64, shading_shift: f64) -> (f64, f64) {
+    let range_min = -shading_shift - (1.0 - shading_toony);
+    let range_max = -shading_shift + (1.0 - shading_toony);
+    let shade_shift = range_min.clamp(-1.0, 1.0);
+    let shade_toony = if shade_shift < 1.0 {
+        ((1.0 - range_max) / (1.0 - shade_shift)).clamp(0.0, 1.0)
+    } else {
+        1.0
+    };
+    (shade_toony, shade_shift)
+}
@@ -0,0 +1,219 @@
+use super::{DowngradeReport, UnsupportedFeature};
+use crate::{
+    migrate::migrate_vector3,
+    vrm_0_0::{
+        Collider, OptionalVector3, VRMSecondaryAnimation, VRMSecondaryAnimationColliderGroup,
+        VRMSecondaryAnimationSpring,
+    },
+    vrmc_spring_bone_1_0::{
+        ColliderShapeKind, SpringBoneJoint, VrmcSpringBoneSchema, VRMC_SPRING_BONE,
+    },
+    vrmc_spring_bone_extended_collider_1_0::VRMC_SPRING_BONE_EXTENDED_COLLIDER,
+};
+
+/// Converts the `VRMC_springBone` extension of VRM 1.0 to `secondaryAnimation` of the `VRM`
+/// extension of VRM 0.x.
+///
+/// As the colliders of a VRM 0.x collider group share a node, each collider group is split by the
+/// nodes of its colliders. Each spring becomes a bone group whose root is the first joint, and the
+/// parameters of the first joint are used for the whole group.
+pub fn downgrade_spring_bone(
+    spring_bone: &VrmcSpringBoneSchema,
+    doc: &gltf::Document,
+    report: &mut DowngradeReport,
+) -> VRMSecondaryAnimation {
+    let pointer = format!("/extensions/{}", VRMC_SPRING_BONE);
+    let colliders = spring_bone.colliders.as_deref().unwrap_or_default();
+
+    let mut collider_groups: Vec<VRMSecondaryAnimationColliderGroup> = Vec::new();
+    // indices of the VRM 0.x collider groups for each VRM 1.0 collider group
+    let mut split_groups = Vec::new();
+    for (i, group) in spring_bone.collider_groups.iter().flatten().enumerate() {
+        let first = collider_groups.len();
+        for (j, &index) in group.colliders.iter().enumerate() {
+            let Some(collider) = colliders.get(index) else {
+                report.push(
+                    UnsupportedFeature::UnresolvedReference,
+                    format!("{}/colliderGroups/{}/colliders/{}", pointer, i, j),
+                    format!("collider {} does not exist", index),
+                );
+                continue;
+            };
+            let collider_pointer = format!("{}/colliders/{}", pointer, index);
+            if collider.extended_collider().is_some() {
+                report.push(
+                    UnsupportedFeature::ExtendedCollider,
+                    format!(
+                        "{}/extensions/{}",
+                        collider_pointer, VRMC_SPRING_BONE_EXTENDED_COLLIDER
+                    ),
+                    "extended colliders are not supported in VRM 0.x; the standard shape is used",
+                );
+            }
+            let (offset, radius) = match collider.shape.kind() {
+                Ok(ColliderShapeKind::Sphere { offset, radius }) => (offset, radius),
+                Ok(ColliderShapeKind::Capsule { offset, radius, .. }) => {
+                    report.push(
+                        UnsupportedFeature::CapsuleCollider,
+                        format!("{}/shape/capsule", collider_pointer),
+                        "capsule colliders are not supported in VRM 0.x; \
+                         a sphere at the head of the capsule is used",
+                    );
+                    (offset, radius)
+                }
+                Err(e) => match &collider.shape.sphere {
+                    // NOTE: the sphere wins if both are given
+                    Some(sphere) => {
+                        report.push(
+                            UnsupportedFeature::InvalidColliderShape,
+                            format!("{}/shape", collider_pointer),
+                            format!("{}; the sphere is used", e),
+                        );
+                        (
+                            sphere.offset.unwrap_or_default(),
+                            sphere.radius.unwrap_or_default(),
+                        )
+                    }
+                    None => {
+                        report.push(
+                            UnsupportedFeature::InvalidColliderShape,
+                            format!("{}/shape", collider_pointer),
+                            format!("{}; the collider is dropped", e),
+                        );
+                        continue;
+                    }
+                },
+            };
+
+            let position = collider_groups[first..]
+                .iter()
+                .position(|group| group.node == Some(collider.node));
+            let group = match position {
+                Some(position) => &mut collider_groups[first + position],
+                None => {
+                    collider_groups.push(VRMSecondaryAnimationColliderGroup {
+                        node: Some(collider.node),
+                        ..Default::default()
+                    });
+                    collider_groups.last_mut().expect("pushed")
+                }
+            };
+            group.colliders.get_or_insert_with(Vec::new).push(Collider {
+                offset: Some(vector3(offset)),
+                radius: Some(radius),
+                #[cfg(feature = "preserve_unknown_fields")]
+                unknown_fields: Default::default(),
+            });
+        }
+        split_groups.push(first as i64..collider_groups.len() as i64);
+    }
+
+    let mut bone_groups = Vec::new();
+    for (i, spring) in spring_bone.springs.iter().flatten().enumerate() {
+        let pointer = format!("{}/springs/{}", pointer, i);
+        let Some(root) = spring.joints.first() else {
+            continue;
+        };
+
+        // NOTE: the parameters of the last joint are not used as it is the tail
+        let swaying = &spring.joints[..spring.joints.len() - 1];
+        if swaying
+            .iter()
+            .any(|joint| parameters(joint) != parameters(root))
+        {
+            report.push(
+                UnsupportedFeature::SpringJointParameters,
+                format!("{}/joints", pointer),
+                "VRM 0.x shares the parameters in a bone group; the ones of the first joint are used",
+            );
+        }
+
+        // VRM 0.x sways all the descendants of the root bone
+        let nodes: Vec<_> = spring
+            .joints
+            .iter()
+            .map(|joint| doc.nodes().nth(joint.node.value()))
+            .collect();
+        let is_chain = nodes.iter().enumerate().all(|(j, node)| {
+            let next = spring.joints.get(j + 1).map(|joint| joint.node.value());
+            node.as_ref().map_or(false, |node| {
+                node.children().all(|child| Some(child.index()) == next)
+            })
+        });
+        if !is_chain {
+            report.push(
+                UnsupportedFeature::SpringChain,
+                format!("{}/joints", pointer),
+                "VRM 0.x sways all the descendants of the first joint, not only the joints",
+            );
+        }
+
+        let mut collider_group_indices = Vec::new();
+        for (j, &group) in spring.collider_groups.iter().flatten().enumerate() {
+            match usize::try_from(group)
+                .ok()
+                .and_then(|group| split_groups.get(group))
+            {
+                Some(indices) => collider_group_indices.extend(indices.clone()),
+                None => report.push(
+                    UnsupportedFeature::UnresolvedReference,
+                    format!("{}/colliderGroups/{}", pointer, j),
+                    format!("collider group {} does not exist", group),
+                ),
+            }
+        }
+
+        bone_groups.push(VRMSecondaryAnimationSpring {
+            bones: Some(vec![root.node]),
+            center: spring.center,
+            collider_groups: Some(collider_group_indices),
+            comment: spring.name.clone(),
+            // NOTE: fill the defaults of VRM 1.0 as VRM 0.x doesn't define them
+            drag_force: Some(root.drag_force.unwrap_or(0.5)),
+            gravity_dir: Some(vector3(root.gravity_dir.unwrap_or([0.0, -1.0, 0.0]))),
+            gravity_power: Some(root.gravity_power.unwrap_or(0.0)),
+            hit_radius: Some(root.hit_radius.unwrap_or(0.0)),
+            stiffiness: Some(root.stiffness.unwrap_or(1.0)),
+            #[cfg(feature = "preserve_unknown_fields")]
+            unknown_fields: Default::default(),
+        });
+    }
+
+    VRMSecondaryAnimation {
+        bone_groups: Some(bone_groups),
+        collider_groups: Some(collider_groups),
+        #[cfg(feature = "preserve_unknown_fields")]
+        unknown_fields: Default::default(),
+    }
+}
+
+type JointParameters = (
+    Option<f64>,
+    Option<[f64; 3]>,
+    Option<f64>,
+    Option<f64>,
+    Option<f64>,
+);
+
+fn parameters(joint: &SpringBoneJoint) -> JointParameters {
+    (
+        joint.drag_force,
+        joint.gravity_dir,
+        joint.gravity_power,
+        joint.hit_radius,
+        joint.stiffness,
+    )
+}
+
+/// Converts a vector to the left-handed coordinate of VRM 0.x
+fn vector3([x, y, z]: [f64; 3]) -> OptionalVector3 {
+    // NOTE: flipping X is its own inverse
+    let [x, y, z] = migrate_vector3(x, y, z);
+    OptionalVector3 {
+        x: Some(x),
+        y: Some(y),
+        z: Some(z),
+        #[cfg(feature = "preserve_unknown_fields")]
+        unknown_fields: Default::default(),
+    }
+}
@@ -0,0 +1,511 @@
+use gltf::json::Index;
+
+use super::{linear_to_gamma, DowngradeReport, UnsupportedFeature};
+use crate::{
+    error::push_pointer_token,
+    migrate::{cc_license_url, migrate_vector3, name_of, LINEAR_CURVE},
+    vrm_0_0::{
+        AllowedUserName, LicenseName, LookAtTypeName, OptionalVector3, PresetName, UssageName,
+        VRM0Schema, VRMBlendShape, VRMBlendShapeBind, VRMBlendShapeGroup,
+        VRMBlendShapeMaterialBind, VRMFirstPerson, VRMFirstPersonDegreeMap,
+        VRMFirstPersonMeshAnnotation, VRMHumanoid, VRMHumanoidBone, VRMMeta,
+    },
+    vrmc_vrm_1_0::{
+        AvatarPermissionType, CommercialUsageType, Expression, ExpressionOverrideType, Expressions,
+        FirstPerson, FirstPersonType, HumanBoneName, Humanoid, LookAt, LookAtRangeMap, LookAtType,
+        MaterialColorType, Meta, VRMCVrmSchema, VRMC_VRM,
+    },
+};
+
+/// Creative Commons licenses of VRM 0.x
+const CC_LICENSES: [LicenseName; 7] = [
+    LicenseName::Cc0,
+    LicenseName::CcBy,
+    LicenseName::CcByNc,
+    LicenseName::CcByNcNd,
+    LicenseName::CcByNcSa,
+    LicenseName::CcByNd,
+    LicenseName::CcBySa,
+];
+
+/// Converts the `VRMC_vrm` extension of VRM 1.0 to the `VRM` extension of VRM 0.x.
+///
+/// `materialProperties` and `secondaryAnimation` are left empty. See
+/// [`downgrade_vrm1`](super::downgrade_vrm1) to convert them together.
+pub fn downgrade_vrmc_vrm(
+    vrmc_vrm: &VRMCVrmSchema,
+    doc: &gltf::Document,
+    report: &mut DowngradeReport,
+) -> VRM0Schema {
+    let pointer = format!("/extensions/{}", VRMC_VRM);
+    VRM0Schema {
+        blend_shape_master: vrmc_vrm
+            .expressions
+            .as_ref()
+            .map(|expressions| downgrade_expressions(expressions, doc, &pointer, report)),
+        first_person: Some(downgrade_first_person(
+            vrmc_vrm.first_person.as_ref(),
+            vrmc_vrm.look_at.as_ref(),
+            &vrmc_vrm.humanoid,
+            doc,
+            &pointer,
+            report,
+        )),
+        humanoid: Some(downgrade_humanoid(&vrmc_vrm.humanoid)),
+        meta: Some(downgrade_meta(&vrmc_vrm.meta, doc, &pointer, report)),
+        spec_version: Some("0.0".to_owned()),
+        ..Default::default()
+    }
+}
+
+fn downgrade_humanoid(humanoid: &Humanoid) -> VRMHumanoid {
+    let mut human_bones: Vec<_> = humanoid
+        .human_bones
+        .0
+        .iter()
+        .filter_map(|(&name, bone)| {
+            Some(VRMHumanoidBone {
+                bone: Some(name.into()),
+                node: Some(bone.as_ref()?.node?),
+                use_default_values: Some(true),
+                ..Default::default()
+            })
+        })
+        .collect();
+    human_bones.sort_by_key(|bone| bone.node.map(|node| node.value()));
+
+    VRMHumanoid {
+        human_bones: Some(human_bones),
+        ..Default::default()
+    }
+}
+
+fn downgrade_meta(
+    meta: &Meta,
+    doc: &gltf::Document,
+    pointer: &str,
+    report: &mut DowngradeReport,
+) -> VRMMeta {
+    let pointer = format!("{}/meta", pointer);
+
+    let texture = meta.thumbnail_image.and_then(|image| {
+        let texture = doc
+            .textures()
+            .find(|texture| texture.source().index() == image.value());
+        if texture.is_none() {
+            report.push(
+                UnsupportedFeature::ThumbnailImage,
+                format!("{}/thumbnailImage", pointer),
+                "VRM 0.x refers to the thumbnail by a texture, but no texture refers to the image",
+            );
+        }
+        texture.map(|texture| Index::new(texture.index() as u32))
+    });
+
+    let cc_license = CC_LICENSES
+        .into_iter()
+        .find(|&name| cc_license_url(name) == meta.other_license_url.as_deref());
+    let (license_name, other_license_url, other_permission_url) = match cc_license {
+        Some(name) => (name, None, None),
+        None => {
+            if meta.allow_redistribution.is_some()
+                || meta.modification.is_some()
+                || meta.credit_notation.is_some()
+            {
+                report.push(
+                    UnsupportedFeature::MetaPermission,
+                    &pointer,
+                    "allowRedistribution, modification and creditNotation are not defined in \
+                     VRM 0.x; otherLicenseUrl refers to the VRM 1.0 license",
+                );
+            }
+            (
+                LicenseName::Other,
+                Some(meta.license_url.clone()),
+                meta.other_license_url.clone(),
+            )
+        }
+    };
+
+    let commercial_ussage_name = match meta.commercial_usage {
+        Some(CommercialUsageType::Corporation) => UssageName::Allow,
+        Some(CommercialUsageType::PersonalProfit) => {
+            report.push(
+                UnsupportedFeature::MetaPermission,
+                format!("{}/commercialUsage", pointer),
+                "personalProfit is not defined in VRM 0.x; commercial usage is disallowed",
+            );
+            UssageName::Disallow
+        }
+        Some(CommercialUsageType::PersonalNonProfit) | None => UssageName::Disallow,
+    };
+
+    let allowed_user_name = match meta.avatar_permission {
+        Some(AvatarPermissionType::Everyone) => AllowedUserName::Everyone,
+        Some(AvatarPermissionType::OnlySeparatelyLicensedPerson) => {
+            AllowedUserName::ExplicitlyLicensedPerson
+        }
+        Some(AvatarPermissionType::OnlyAuthor) | None => AllowedUserName::OnlyAuthor,
+    };
+
+    for (name, allowed) in [
+        (
+            "allowPoliticalOrReligiousUsage",
+            meta.allow_political_or_religious_usage,
+        ),
+        (
+            "allowAntisocialOrHateUsage",
+            meta.allow_antisocial_or_hate_usage,
+        ),
+    ] {
+        if allowed == Some(true) {
+            report.push(
+                UnsupportedFeature::MetaPermission,
+                format!("{}/{}", pointer, name),
+                format!("{} is not defined in VRM 0.x and dropped", name),
+            );
+        }
+    }
+    for (name, value) in [
+        ("copyrightInformation", &meta.copyright_information),
+        ("thirdPartyLicenses", &meta.third_party_licenses),
+    ] {
+        if value.is_some() {
+            report.push(
+                UnsupportedFeature::MetaField,
+                format!("{}/{}", pointer, name),
+                format!("{} is not defined in VRM 0.x and dropped", name),
+            );
+        }
+    }
+
+    let usage = |allowed: Option<bool>| match allowed {
+        Some(true) => UssageName::Allow,
+        Some(false) | None => UssageName::Disallow,
+    };
+
+    VRMMeta {
+        allowed_user_name: Some(allowed_user_name),
+        author: Some(meta.authors.join(", ")),
+        commercial_ussage_name: Some(commercial_ussage_name),
+        contact_information: meta.contact_information.clone(),
+        license_name: Some(license_name),
+        other_license_url,
+        other_permission_url,
+        reference: meta
+            .references
+            .as_ref()
+            .map(|references| references.join(", ")),
+        sexual_ussage_name: Some(usage(meta.allow_excessively_sexual_usage)),
+        texture,
+        title: Some(meta.name.clone()),
+        version: meta.version.clone(),
+        violent_ussage_name: Some(usage(meta.allow_excessively_violent_usage)),
+        #[cfg(feature = "preserve_unknown_fields")]
+        unknown_fields: Default::default(),
+    }
+}
+
+fn downgrade_expressions(
+    expressions: &Expressions,
+    doc: &gltf::Document,
+    pointer: &str,
+    report: &mut DowngradeReport,
+) -> VRMBlendShape {
+    let mut blend_shape_groups = Vec::new();
+
+    // NOTE: sort by name to make the output stable
+    let mut preset: Vec<_> = expressions
+        .preset
+        .iter()
+        .flat_map(|preset| &preset.0)
+        .map(|(&name, expression)| (name_of(&name), name, expression))
+        .collect();
+    preset.sort_by(|a, b| a.0.cmp(&b.0));
+    for (key, name, expression) in preset {
+        let mut pointer = format!("{}/expressions/preset", pointer);
+        push_pointer_token(&mut pointer, &key);
+        let preset_name = PresetName::try_from(name).unwrap_or_else(|_| {
+            report.push(
+                UnsupportedFeature::ExpressionPreset,
+                &pointer,
+                format!(
+                    "{} has no preset in VRM 0.x; it is a custom blend shape",
+                    key
+                ),
+            );
+            PresetName::Unknown
+        });
+        blend_shape_groups.push(downgrade_expression(
+            expression,
+            key,
+            preset_name,
+            doc,
+            &pointer,
+            report,
+        ));
+    }
+
+    let mut custom: Vec<_> = expressions.custom.iter().flatten().collect();
+    custom.sort_by(|a, b| a.0.cmp(b.0));
+    for (key, expression) in custom {
+        let mut pointer = format!("{}/expressions/custom", pointer);
+        push_pointer_token(&mut pointer, key);
+        blend_shape_groups.push(downgrade_expression(
+            expression,
+            key.clone(),
+            PresetName::Unknown,
+            doc,
+            &pointer,
+            report,
+        ));
+    }
+
+    VRMBlendShape {
+        blend_shape_groups: Some(blend_shape_groups),
+        #[cfg(feature = "preserve_unknown_fields")]
+        unknown_fields: Default::default(),
+    }
+}
+
+fn downgrade_expression(
+    expression: &Expression,
+    name: String,
+    preset_name: PresetName,
+    doc: &gltf::Document,
+    pointer: &str,
+    report: &mut DowngradeReport,
+) -> VRMBlendShapeGroup {
+    let mut binds = Vec::new();
+    let mut bound = Vec::new();
+    for (i, bind) in expression.morph_target_binds.iter().flatten().enumerate() {
+        let Some(mesh) = node_mesh(doc, bind.node.value()) else {
+            report.push(
+                UnsupportedFeature::UnresolvedReference,
+                format!("{}/morphTargetBinds/{}", pointer, i),
+                format!("node {} has no mesh; the bind is dropped", bind.node),
+            );
+            continue;
+        };
+        // NOTE: nodes which share a mesh are bound once as VRM 0.x binds a mesh
+        if bound.contains(&(mesh, bind.index)) {
+            continue;
+        }
+        bound.push((mesh, bind.index));
+        binds.push(VRMBlendShapeBind {
+            index: Some(bind.index as i64),
+            mesh: Some(Index::new(mesh as u32)),
+            weight: Some(bind.weight * 100.0),
+            #[cfg(feature = "preserve_unknown_fields")]
+            unknown_fields: Default::default(),
+        });
+    }
+
+    let mut material_values = Vec::new();
+    for (i, bind) in expression.material_color_binds.iter().flatten().enumerate() {
+        let pointer = format!("{}/materialColorBinds/{}", pointer, i);
+        let Some(material_name) = doc
+            .materials()
+            .nth(bind.material.value())
+            .and_then(|material| material.name())
+        else {
+            report.push(
+                UnsupportedFeature::UnresolvedReference,
+                pointer,
+                format!(
+                    "VRM 0.x refers to materials by name, but material {} has no name",
+                    bind.material
+                ),
+            );
+            continue;
+        };
+        let (property_name, linear) = match bind.material_color_bind_type {
+            MaterialColorType::Color => ("_Color", false),
+            MaterialColorType::EmissionColor => ("_EmissionColor", true),
+            MaterialColorType::OutlineColor => ("_OutlineColor", false),
+            MaterialColorType::RimColor => ("_RimColor", false),
+            MaterialColorType::ShadeColor => ("_ShadeColor", false),
+            MaterialColorType::MatcapColor => {
+                report.push(
+                    UnsupportedFeature::MaterialColorBind,
+                    pointer,
+                    "matcapColor has no MToon 0.x property; the bind is dropped",
+                );
+                continue;
+            }
+        };
+        let [r, g, b, a] = match bind.target_value[..] {
+            [r, g, b, a] => [r, g, b, a],
+            _ => {
+                report.push(
+                    UnsupportedFeature::UnresolvedReference,
+                    pointer,
+                    "targetValue is not a vector4; the bind is dropped",
+                );
+                continue;
+            }
+        };
+        let target_value = if linear {
+            vec![r, g, b, a]
+        } else {
+            vec![
+                linear_to_gamma(r),
+                linear_to_gamma(g),
+                linear_to_gamma(b),
+                a,
+            ]
+        };
+        material_values.push(VRMBlendShapeMaterialBind {
+            material_name: Some(material_name.to_owned()),
+            property_name: Some(property_name.to_owned()),
+            target_value: Some(target_value),
+            #[cfg(feature = "preserve_unknown_fields")]
+            unknown_fields: Default::default(),
+        });
+    }
+
+    for i in 0..expression
+        .texture_transform_binds
+        .as_ref()
+        .map_or(0, Vec::len)
+    {
+        report.push(
+            UnsupportedFeature::TextureTransformBind,
+            format!("{}/textureTransformBinds/{}", pointer, i),
+            "texture transform binds are not supported in VRM 0.x and dropped",
+        );
+    }
+
+    for (name, override_type) in [
+        ("overrideBlink", expression.override_blink),
+        ("overrideLookAt", expression.override_look_at),
+        ("overrideMouth", expression.override_mouth),
+    ] {
+        if matches!(
+            override_type,
+            Some(ExpressionOverrideType::Blend | ExpressionOverrideType::Block)
+        ) {
+            report.push(
+                UnsupportedFeature::ExpressionOverride,
+                format!("{}/{}", pointer, name),
+                format!("{} is not supported in VRM 0.x and dropped", name),
+            );
+        }
+    }
+
+    VRMBlendShapeGroup {
+        binds: Some(binds),
+        is_binary: expression.is_binary,
+        material_values: Some(material_values),
+        name: Some(name),
+        preset_name: Some(preset_name),
+        #[cfg(feature = "preserve_unknown_fields")]
+        unknown_fields: Default::default(),
+    }
+}
+
+fn downgrade_first_person(
+    first_person: Option<&FirstPerson>,
+    look_at: Option<&LookAt>,
+    humanoid: &Humanoid,
+    doc: &gltf::Document,
+    pointer: &str,
+    report: &mut DowngradeReport,
+) -> VRMFirstPerson {
+    let head = humanoid
+        .human_bones
+        .0
+        .get(&HumanBoneName::Head)
+        .and_then(|bone| bone.as_ref()?.node);
+
+    let mut mesh_annotations: Vec<VRMFirstPersonMeshAnnotation> = Vec::new();
+    let annotations = first_person.and_then(|first_person| first_person.mesh_annotations.as_ref());
+    for (i, annotation) in annotations.into_iter().flatten().enumerate() {
+        let Some(mesh) = annotation
+            .node
+            .and_then(|node| node_mesh(doc, node.value()))
+        else {
+            report.push(
+                UnsupportedFeature::UnresolvedReference,
+                format!("{}/firstPerson/meshAnnotations/{}", pointer, i),
+                "the node has no mesh; the annotation is dropped",
+            );
+            continue;
+        };
+        // NOTE: nodes which share a mesh are annotated once as VRM 0.x annotates a mesh
+        if mesh_annotations
+            .iter()
+            .any(|annotation| annotation.mesh.map(|mesh| mesh.value()) == Some(mesh))
+        {
+            continue;
+        }
+        let flag = match annotation.mesh_annotation_type {
+            FirstPersonType::Auto => "Auto",
+            FirstPersonType::Both => "Both",
+            FirstPersonType::FirstPersonOnly => "FirstPersonOnly",
+            FirstPersonType::ThirdPersonOnly => "ThirdPersonOnly",
+        };
+        mesh_annotations.push(VRMFirstPersonMeshAnnotation {
+            first_person_flag: Some(flag.to_owned()),
+            mesh: Some(Index::new(mesh as u32)),
+            #[cfg(feature = "preserve_unknown_fields")]
+            unknown_fields: Default::default(),
+        });
+    }
+
+    let degree_map = |range_map: Option<&LookAtRangeMap>| {
+        range_map.map(|range_map| VRMFirstPersonDegreeMap {
+            curve: Some(LINEAR_CURVE.to_vec()),
+            x_range: range_map.input_max_value,
+            y_range: range_map.output_scale,
+            #[cfg(feature = "preserve_unknown_fields")]
+            unknown_fields: Default::default(),
+        })
+    };
+
+    VRMFirstPerson {
+        first_person_bone: head,
+        first_person_bone_offset: look_at
+            .and_then(|look_at| look_at.offset_from_head_bone.as_deref())
+            .and_then(|offset| match offset {
+                &[x, y, z] => {
+                    // NOTE: flipping X is its own inverse
+                    let [x, y, z] = migrate_vector3(x, y, z);
+                    Some(OptionalVector3 {
+                        x: Some(x),
+                        y: Some(y),
+                        z: Some(z),
+                        #[cfg(feature = "preserve_unknown_fields")]
+                        unknown_fields: Default::default(),
+                    })
+                }
+                _ => None,
+            }),
+        look_at_horizontal_inner: degree_map(
+            look_at.and_then(|look_at| look_at.range_map_horizontal_inner.as_ref()),
+        ),
+        look_at_horizontal_outer: degree_map(
+            look_at.and_then(|look_at| look_at.range_map_horizontal_outer.as_ref()),
+        ),
+        look_at_type_name: look_at
+            .and_then(|look_at| look_at.look_at_type)
+            .map(|look_at_type| match look_at_type {
+                LookAtType::Bone => LookAtTypeName::Bone,
+                LookAtType::Expression => LookAtTypeName::BlendShape,
+            }),
+        look_at_vertical_down: degree_map(
+            look_at.and_then(|look_at| look_at.range_map_vertical_down.as_ref()),
+        ),
+        look_at_vertical_up: degree_map(
+            look_at.and_then(|look_at| look_at.range_map_vertical_up.as_ref()),
+        ),
+        mesh_annotations: Some(mesh_annotations),
+        #[cfg(feature = "preserve_unknown_fields")]
+        unknown_fields: Default::default(),
+    }
+}
+
+/// Returns the index of the mesh of the node
+fn node_mesh(doc: &gltf::Document, node: usize) -> Option<usize> {
+    Some(doc.nodes().nth(node)?.mesh()?.index())
+}
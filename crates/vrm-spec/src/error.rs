@@ -0,0 +1,103 @@
+//! Error type of this crate.
+
+use std::fmt;
+
+use serde::de::DeserializeOwned;
+
+/// Error while loading VRM data structures
+#[derive(Debug)]
+pub enum Error {
+    /// Failed to load the glTF or GLB
+    #[cfg(feature = "gltf_index")]
+    Gltf(gltf::Error),
+
+    /// The document has none of the expected extensions
+    MissingExtension(&'static [&'static str]),
+
+    /// Failed to deserialize a VRM data structure
+    Deserialize {
+        /// [JSON pointer](https://datatracker.ietf.org/doc/html/rfc6901) to the field that failed
+        /// to deserialize, e.g. `/humanoid/humanBones/leftHand/node`. It is relative to the value
+        /// passed to [`from_value`], and to the root of the glTF JSON when loading a
+        /// [`VrmDocument`](crate::document::VrmDocument).
+        pointer: String,
+        source: serde_json::Error,
+    },
+
+    /// Failed to serialize a VRM data structure
+    Serialize(serde_json::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            #[cfg(feature = "gltf_index")]
+            Error::Gltf(e) => write!(f, "failed to load glTF: {}", e),
+            Error::MissingExtension(names) => {
+                write!(f, "missing extension: {}", names.join(" or "))
+            }
+            Error::Deserialize { pointer, source } => {
+                write!(f, "failed to deserialize '{}': {}", pointer, source)
+            }
+            Error::Serialize(e) => write!(f, "failed to serialize: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            #[cfg(feature = "gltf_index")]
+            Error::Gltf(e) => Some(e),
+            Error::MissingExtension(_) => None,
+            Error::Deserialize { source, .. } => Some(source),
+            Error::Serialize(e) => Some(e),
+        }
+    }
+}
+
+#[cfg(feature = "gltf_index")]
+impl From<gltf::Error> for Error {
+    fn from(e: gltf::Error) -> Self {
+        Error::Gltf(e)
+    }
+}
+
+/// Deserializes a VRM data structure like `serde_json::from_value`, but reports the JSON pointer
+/// of the field that failed to deserialize.
+pub fn from_value<T>(value: &serde_json::Value) -> Result<T, Error>
+where
+    T: DeserializeOwned,
+{
+    from_value_at(value, "")
+}
+
+/// Same as [`from_value`], but the reported JSON pointer is prefixed by `base` which is the
+/// location of `value`.
+pub(crate) fn from_value_at<T>(value: &serde_json::Value, base: &str) -> Result<T, Error>
+where
+    T: DeserializeOwned,
+{
+    serde_path_to_error::deserialize(value).map_err(|e| {
+        let mut pointer = base.to_owned();
+        for segment in e.path().iter() {
+            use serde_path_to_error::Segment;
+            match segment {
+                Segment::Seq { index } => push_pointer_token(&mut pointer, &index.to_string()),
+                Segment::Map { key } => push_pointer_token(&mut pointer, key),
+                Segment::Enum { variant } => push_pointer_token(&mut pointer, variant),
+                Segment::Unknown => {}
+            }
+        }
+        Error::Deserialize {
+            pointer,
+            source: e.into_inner(),
+        }
+    })
+}
+
+/// Appends a reference token to a JSON pointer, escaping `~` and `/`
+pub(crate) fn push_pointer_token(pointer: &mut String, token: &str) {
+    pointer.push('/');
+    pointer.push_str(&token.replace('~', "~0").replace('/', "~1"));
+}
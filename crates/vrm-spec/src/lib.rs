@@ -16,9 +16,51 @@
 //!
 //! // do something with vrm
 //! ```
+//!
+//! ## Features
+//!
+//! - `rustc_hash` (default): uses `FxHashMap` of `rustc-hash` for the maps.
+//! - `gltf_index` (default): uses the index types of `gltf` and enables the modules which work
+//!   on a glTF document.
+//! - `preserve_unknown_fields`: captures properties which are not defined in the schema into
+//!   `unknown_fields` of each struct, and writes them back on serialization.
+//!
+//! **`preserve_unknown_fields` is not additive.** It adds a public `unknown_fields` field to the
+//! schema structs, so struct literals without that field stop compiling as soon as any crate in
+//! the dependency graph enables the feature. Crates that build schema structs with struct
+//! literals should enable the feature themselves and set `unknown_fields`.
+//!
+//! Even with `preserve_unknown_fields`, a round trip doesn't keep the following:
+//!
+//! - Indices of VRM 0.x which Unity writes as `-1` for "none", e.g. `meta.texture` or the `node`
+//!   of a human bone, are read as `None` and omitted on serialization.
+//! - `null` values in `floatProperties` of VRM 0.x materials are dropped.
+//! - Unknown properties of the sphere and capsule collider shapes of `VRMC_springBone` and
+//!   `VRMC_springBone_extended_collider` are dropped, as those types don't have
+//!   `unknown_fields` so that they stay `Copy`.
+//! - Numbers are written as they are typed, e.g. `1` of a `f64` property is written as `1.0`.
 
+#[cfg(feature = "gltf_index")]
+pub mod coordinate;
+#[cfg(feature = "gltf_index")]
+pub mod document;
+#[cfg(feature = "gltf_index")]
+pub mod downgrade;
+mod error;
+#[cfg(feature = "gltf_index")]
+pub mod migrate;
 mod serde_utils;
+#[cfg(feature = "gltf_index")]
+pub mod validate;
 pub mod vrm_0_0;
+pub mod vrmc_materials_hdr_emissive_multiplier_1_0;
 pub mod vrmc_materials_mtoon_1_0;
+pub mod vrmc_node_constraint_1_0;
 pub mod vrmc_spring_bone_1_0;
+pub mod vrmc_spring_bone_extended_collider_1_0;
 pub mod vrmc_vrm_1_0;
+pub mod vrmc_vrm_animation_1_0;
+#[cfg(feature = "gltf_index")]
+pub mod writer;
+
+pub use error::{from_value, Error};
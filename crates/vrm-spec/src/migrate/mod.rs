@@ -0,0 +1,118 @@
+//! Migration of VRM 0.x data structures to VRM 1.0.
+//!
+//! The migration follows the rules of
+//! [UniVRM](https://github.com/vrm-c/UniVRM/tree/master/Assets/VRM10/Runtime/Migration). Decisions
+//! which lose or guess information are collected into a [`MigrationReport`].
+//!
+//! Vector values, such as the lookAt offset, are converted into the VRM 1.0 model space, which
+//! faces +Z. The geometry is not, so use
+//! [`coordinate::rotate_gltf`](crate::coordinate::rotate_gltf) to rotate the nodes, meshes and
+//! animations as well.
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! use vrm_spec::{migrate, vrm_0_0};
+//!
+//! let file = std::fs::read("model.vrm").expect("ok");
+//! let (doc, _, _) = gltf::import_slice(&file).expect("ok");
+//! let value = doc.extension_value(vrm_0_0::VRM).expect("exist");
+//! let vrm0: vrm_0_0::VRM0Schema = serde_json::from_value(value.to_owned()).expect("ok");
+//!
+//! let (vrmc_vrm, mut report) = migrate::migrate_vrm0(&vrm0, &doc);
+//! let vrmc_spring_bone = vrm0.secondary_animation.as_ref().map(|secondary_animation| {
+//!     migrate::migrate_spring_bone(secondary_animation, &doc, &mut report)
+//! });
+//! for note in report.notes {
+//!     println!("{}: {}", note.pointer, note.message);
+//! }
+//! ```
+
+mod vrmc_materials_mtoon;
+mod vrmc_spring_bone;
+mod vrmc_vrm;
+
+pub use vrmc_materials_mtoon::{migrate_materials, MigratedMaterial};
+pub(crate) use vrmc_materials_mtoon::{GLTF_SHADER, MTOON_SHADER};
+pub use vrmc_spring_bone::migrate_spring_bone;
+pub use vrmc_vrm::{
+    migrate_expressions, migrate_first_person, migrate_humanoid, migrate_meta, migrate_vrm0,
+};
+
+use serde::Serialize;
+
+use crate::vrm_0_0::LicenseName;
+
+/// Decisions of a migration which lose or guess information
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct MigrationReport {
+    pub notes: Vec<MigrationNote>,
+}
+
+/// A decision of a migration which loses or guesses information
+#[derive(Debug, Clone, Serialize)]
+pub struct MigrationNote {
+    /// JSON pointer to the source property, relative to the migrated extension, e.g.
+    /// `/humanoid/humanBones/3`
+    pub pointer: String,
+
+    /// Description of the decision
+    pub message: String,
+}
+
+impl MigrationReport {
+    pub(crate) fn note(&mut self, pointer: impl Into<String>, message: impl Into<String>) {
+        self.notes.push(MigrationNote {
+            pointer: pointer.into(),
+            message: message.into(),
+        });
+    }
+}
+
+/// `curve` of VRM 0.x lookAt which maps the input linearly
+pub(crate) const LINEAR_CURVE: [f64; 8] = [0.0, 0.0, 0.0, 1.0, 1.0, 1.0, 1.0, 0.0];
+
+/// Converts a color component from gamma (sRGB) to linear
+pub(crate) fn gamma_to_linear(c: f64) -> f64 {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Converts a vector from the VRM 0.x extension, which is left-handed in Unity, to the VRM 1.0
+/// model space
+pub(crate) fn migrate_vector3(x: f64, y: f64, z: f64) -> [f64; 3] {
+    [-x, y, z]
+}
+
+/// Returns the indices of the nodes which have the mesh
+pub(crate) fn mesh_nodes(doc: &gltf::Document, mesh: usize) -> Vec<usize> {
+    doc.nodes()
+        .filter(|node| node.mesh().map(|m| m.index()) == Some(mesh))
+        .map(|node| node.index())
+        .collect()
+}
+
+/// Returns the name used in JSON
+pub(crate) fn name_of<T: Serialize>(value: &T) -> String {
+    serde_json::to_value(value)
+        .ok()
+        .and_then(|value| value.as_str().map(ToOwned::to_owned))
+        .unwrap_or_default()
+}
+
+/// Returns the URL of the Creative Commons license
+pub(crate) fn cc_license_url(name: LicenseName) -> Option<&'static str> {
+    match name {
+        LicenseName::Cc0 => Some("https://creativecommons.org/publicdomain/zero/1.0/"),
+        LicenseName::CcBy => Some("https://creativecommons.org/licenses/by/4.0/"),
+        LicenseName::CcByNc => Some("https://creativecommons.org/licenses/by-nc/4.0/"),
+        LicenseName::CcByNcNd => Some("https://creativecommons.org/licenses/by-nc-nd/4.0/"),
+        LicenseName::CcByNcSa => Some("https://creativecommons.org/licenses/by-nc-sa/4.0/"),
+        LicenseName::CcByNd => Some("https://creativecommons.org/licenses/by-nd/4.0/"),
+        LicenseName::CcBySa => Some("https://creativecommons.org/licenses/by-sa/4.0/"),
+        LicenseName::RedistributionProhibited | LicenseName::Other => None,
+    }
+}
@@ -0,0 +1,318 @@
+use gltf::json::{
+    material::{
+        AlphaCutoff, AlphaMode, EmissiveFactor, NormalTexture, PbrBaseColorFactor, StrengthFactor,
+    },
+    texture::Info as TextureInfo,
+    validation::Checked,
+};
+
+use super::{gamma_to_linear, MigrationReport};
+use crate::{
+    vrm_0_0::VRMMaterial,
+    vrmc_materials_mtoon_1_0::{OutlineWidthMode, VrmcMaterialsMtoonSchema},
+};
+
+/// Shader name of MToon in VRM 0.x
+pub(crate) const MTOON_SHADER: &str = "VRM/MToon";
+
+/// Shader name which means the material is a standard glTF material in VRM 0.x
+pub(crate) const GLTF_SHADER: &str = "VRM_USE_GLTFSHADER";
+
+/// Name of the glTF extension carrying the texture transform of `_MainTex_ST`
+const KHR_TEXTURE_TRANSFORM: &str = "KHR_texture_transform";
+
+/// Properties of VRM 0.x MToon which have no counterpart in VRM 1.0
+const UNSUPPORTED_FLOAT_PROPERTIES: &[&str] = &[
+    "_LightColorAttenuation",
+    "_OutlineScaledMaxDistance",
+    "_ReceiveShadowRate",
+    "_ShadingGradeRate",
+];
+
+/// A material migrated from MToon of VRM 0.x
+///
+/// If `_MainTex_ST` is not the identity, the textures other than the matcap texture carry
+/// `KHR_texture_transform`, which then has to be added to `extensionsUsed` of the glTF.
+#[derive(Debug, Clone)]
+pub struct MigratedMaterial {
+    /// The core glTF material, which is also the fallback for renderers without MToon support
+    pub material: gltf::json::Material,
+
+    /// The `VRMC_materials_mtoon` extension of the material
+    pub mtoon: VrmcMaterialsMtoonSchema,
+}
+
+/// Migrates `materialProperties` of the `VRM` extension of VRM 0.x to the `VRMC_materials_mtoon`
+/// extension of VRM 1.0.
+///
+/// `materialProperties` of VRM 0.x corresponds to `materials` of the glTF by index, and so does
+/// the returned `Vec`. Materials which don't use MToon are `None`.
+pub fn migrate_materials(
+    material_properties: &[VRMMaterial],
+    report: &mut MigrationReport,
+) -> Vec<Option<MigratedMaterial>> {
+    material_properties
+        .iter()
+        .enumerate()
+        .map(|(i, material)| {
+            let pointer = format!("/materialProperties/{}", i);
+            match material.shader.as_deref() {
+                Some(MTOON_SHADER) => Some(migrate_mtoon(material, &pointer, report)),
+                Some(GLTF_SHADER) => None,
+                shader => {
+                    report.note(
+                        format!("{}/shader", pointer),
+                        format!(
+                            "shader {:?} is not supported; the glTF material is used as is",
+                            shader
+                        ),
+                    );
+                    None
+                }
+            }
+        })
+        .collect()
+}
+
+fn migrate_mtoon(
+    material: &VRMMaterial,
+    pointer: &str,
+    report: &mut MigrationReport,
+) -> MigratedMaterial {
+    let float = |name: &str| {
+        material
+            .float_properties
+            .as_ref()
+            .and_then(|properties| properties.get(name).copied())
+    };
+    let vector = |name: &str| -> Option<[f64; 4]> {
+        match material.vector_properties.as_ref()?.get(name)?[..] {
+            [x, y, z, w] => Some([x, y, z, w]),
+            _ => None,
+        }
+    };
+    // NOTE: _MainTex_ST applies to every texture but the matcap texture, which is sampled in
+    // view space
+    let texture_transform = vector("_MainTex_ST")
+        .filter(|&st| st != [1.0, 1.0, 0.0, 0.0])
+        .map(|[scale_x, scale_y, offset_x, offset_y]| {
+            // NOTE: the origin of UV is bottom-left in Unity and top-left in glTF
+            let transform = serde_json::json!({
+                "offset": [offset_x, 1.0 - offset_y - scale_y],
+                "scale": [scale_x, scale_y],
+            });
+            serde_json::json!({ KHR_TEXTURE_TRANSFORM: transform })
+        });
+    let texture = |name: &str| {
+        material
+            .texture_properties
+            .as_ref()
+            .and_then(|properties| properties.get(name).copied())
+            .map(|index| TextureInfo {
+                index,
+                tex_coord: 0,
+                extensions: texture_transform
+                    .clone()
+                    .and_then(|extensions| serde_json::from_value(extensions).ok()),
+                extras: Default::default(),
+            })
+    };
+    // NOTE: colors other than emission are in gamma space in Unity
+    let linear_color = |name: &str| {
+        vector(name).map(|[r, g, b, a]| {
+            [
+                gamma_to_linear(r),
+                gamma_to_linear(g),
+                gamma_to_linear(b),
+                a,
+            ]
+        })
+    };
+    let rgb = |[r, g, b, _]: [f64; 4]| [r, g, b];
+
+    for name in UNSUPPORTED_FLOAT_PROPERTIES {
+        if float(name).map_or(false, |value| value != 0.0) {
+            report.note(
+                format!("{}/floatProperties/{}", pointer, name),
+                format!("{} is not supported in VRM 1.0 and dropped", name),
+            );
+        }
+    }
+    if texture("_ShadingGradeTexture").is_some() {
+        report.note(
+            format!("{}/textureProperties/_ShadingGradeTexture", pointer),
+            "_ShadingGradeTexture is not supported in VRM 1.0 and dropped",
+        );
+    }
+
+    let mut gltf_material = gltf::json::Material {
+        name: material.name.clone(),
+        ..Default::default()
+    };
+    let mut mtoon = VrmcMaterialsMtoonSchema {
+        extensions: None,
+        extras: None,
+        gi_equalization_factor: None,
+        matcap_factor: None,
+        matcap_texture: None,
+        outline_color_factor: None,
+        outline_lighting_mix_factor: None,
+        outline_width_factor: None,
+        outline_width_mode: None,
+        outline_width_multiply_texture: None,
+        parametric_rim_color_factor: None,
+        parametric_rim_fresnel_power_factor: None,
+        parametric_rim_lift_factor: None,
+        render_queue_offset_number: None,
+        rim_lighting_mix_factor: None,
+        rim_multiply_texture: None,
+        shade_color_factor: None,
+        shade_multiply_texture: None,
+        shading_shift_factor: None,
+        shading_shift_texture: None,
+        shading_toony_factor: None,
+        spec_version: "1.0".to_owned(),
+        transparent_with_z_write: None,
+        uv_animation_mask_texture: None,
+        uv_animation_rotation_speed_factor: None,
+        uv_animation_scroll_x_speed_factor: None,
+        uv_animation_scroll_y_speed_factor: None,
+        #[cfg(feature = "preserve_unknown_fields")]
+        unknown_fields: Default::default(),
+    };
+
+    // rendering
+    // NOTE: -1 or a missing renderQueue means the default queue of the blend mode, which
+    // results in the offset 0
+    let render_queue = |base: i64| {
+        material
+            .render_queue
+            .filter(|&queue| queue != -1)
+            .unwrap_or(base)
+            - base
+    };
+    match float("_BlendMode").map(|mode| mode as i64) {
+        None | Some(0) => gltf_material.alpha_mode = Checked::Valid(AlphaMode::Opaque),
+        Some(1) => {
+            gltf_material.alpha_mode = Checked::Valid(AlphaMode::Mask);
+            gltf_material.alpha_cutoff = float("_Cutoff").map(|cutoff| AlphaCutoff(cutoff as f32));
+        }
+        Some(2) => {
+            gltf_material.alpha_mode = Checked::Valid(AlphaMode::Blend);
+            mtoon.render_queue_offset_number = Some(render_queue(3000).clamp(-9, 0));
+        }
+        Some(3) => {
+            gltf_material.alpha_mode = Checked::Valid(AlphaMode::Blend);
+            mtoon.transparent_with_z_write = Some(true);
+            mtoon.render_queue_offset_number = Some(render_queue(2501).clamp(0, 9));
+        }
+        Some(mode) => report.note(
+            format!("{}/floatProperties/_BlendMode", pointer),
+            format!("_BlendMode {} is unknown; the material is opaque", mode),
+        ),
+    }
+    match float("_CullMode").map(|mode| mode as i64) {
+        Some(0) => gltf_material.double_sided = true,
+        None | Some(2) => gltf_material.double_sided = false,
+        Some(mode) => report.note(
+            format!("{}/floatProperties/_CullMode", pointer),
+            format!("_CullMode {} is not supported; back faces are culled", mode),
+        ),
+    }
+
+    // lighting
+    if let Some([r, g, b, a]) = linear_color("_Color") {
+        gltf_material.pbr_metallic_roughness.base_color_factor =
+            PbrBaseColorFactor([r as f32, g as f32, b as f32, a as f32]);
+    }
+    gltf_material.pbr_metallic_roughness.base_color_texture = texture("_MainTex");
+    gltf_material.pbr_metallic_roughness.metallic_factor = StrengthFactor(0.0);
+    gltf_material.pbr_metallic_roughness.roughness_factor = StrengthFactor(1.0);
+    mtoon.shade_color_factor = linear_color("_ShadeColor").map(rgb);
+    mtoon.shade_multiply_texture = texture("_ShadeTexture");
+    gltf_material.normal_texture = texture("_BumpMap").map(|info| NormalTexture {
+        index: info.index,
+        scale: float("_BumpScale").unwrap_or(1.0) as f32,
+        tex_coord: 0,
+        extensions: texture_transform
+            .clone()
+            .and_then(|extensions| serde_json::from_value(extensions).ok()),
+        extras: Default::default(),
+    });
+
+    let shade_toony = float("_ShadeToony").unwrap_or(0.9);
+    let shade_shift = float("_ShadeShift").unwrap_or(0.0);
+    let (shading_toony, shading_shift) = migrate_shading(shade_toony, shade_shift);
+    mtoon.shading_toony_factor = Some(shading_toony);
+    mtoon.shading_shift_factor = Some(shading_shift);
+
+    // global illumination
+    mtoon.gi_equalization_factor = float("_IndirectLightIntensity").map(|value| 1.0 - value);
+
+    // emission
+    if let Some([r, g, b, _]) = vector("_EmissionColor") {
+        gltf_material.emissive_factor = EmissiveFactor([r as f32, g as f32, b as f32]);
+    }
+    gltf_material.emissive_texture = texture("_EmissionMap");
+
+    // rim lighting
+    mtoon.matcap_texture = texture("_SphereAdd").map(|info| TextureInfo {
+        extensions: None,
+        ..info
+    });
+    if mtoon.matcap_texture.is_some() {
+        mtoon.matcap_factor = Some([1.0, 1.0, 1.0]);
+    }
+    mtoon.parametric_rim_color_factor = linear_color("_RimColor").map(rgb);
+    mtoon.parametric_rim_fresnel_power_factor = float("_RimFresnelPower");
+    mtoon.parametric_rim_lift_factor = float("_RimLift");
+    mtoon.rim_lighting_mix_factor = float("_RimLightingMix");
+    mtoon.rim_multiply_texture = texture("_RimTexture");
+
+    // outline
+    mtoon.outline_width_mode = match float("_OutlineWidthMode").map(|mode| mode as i64) {
+        None | Some(0) => Some(OutlineWidthMode::None),
+        Some(1) => Some(OutlineWidthMode::WorldCoordinates),
+        Some(2) => Some(OutlineWidthMode::ScreenCoordinates),
+        Some(mode) => {
+            report.note(
+                format!("{}/floatProperties/_OutlineWidthMode", pointer),
+                format!(
+                    "_OutlineWidthMode {} is unknown; the outline is disabled",
+                    mode
+                ),
+            );
+            Some(OutlineWidthMode::None)
+        }
+    };
+    // NOTE: the width is in centimeters in VRM 0.x
+    mtoon.outline_width_factor = float("_OutlineWidth").map(|width| width * 0.01);
+    mtoon.outline_width_multiply_texture = texture("_OutlineWidthTexture");
+    mtoon.outline_color_factor = linear_color("_OutlineColor").map(rgb);
+    mtoon.outline_lighting_mix_factor = match float("_OutlineColorMode").map(|mode| mode as i64) {
+        Some(1) => float("_OutlineLightingMix"),
+        _ => Some(0.0),
+    };
+
+    // UV animation
+    mtoon.uv_animation_mask_texture = texture("_UvAnimMaskTexture");
+    mtoon.uv_animation_rotation_speed_factor = float("_UvAnimRotation");
+    mtoon.uv_animation_scroll_x_speed_factor = float("_UvAnimScrollX");
+    // NOTE: the origin of UV is bottom-left in Unity and top-left in glTF
+    mtoon.uv_animation_scroll_y_speed_factor = float("_UvAnimScrollY").map(|speed| -speed);
+
+    MigratedMaterial {
+        material: gltf_material,
+        mtoon,
+    }
+}
+
+/// Converts `_ShadeToony` and `_ShadeShift` of VRM 0.x to `shadingToonyFactor` and
+/// `shadingShiftFactor` of VRM 1.0, keeping the range of the shading gradient.
+fn migrate_shading(shade_toony: f64, shade_shift: f64) -> (f64, f64) {
+    let range_min = shade_shift;
+    let range_max = 1.0 + (shade_shift - 1.0) * shade_toony;
+    let shading_toony = ((2.0 - (range_max - range_min)) * 0.5).clamp(0.0, 1.0);
+    let shading_shift = (-(range_max + range_min) * 0.5).clamp(-1.0, 1.0);
+    (shading_toony, shading_shift)
+}
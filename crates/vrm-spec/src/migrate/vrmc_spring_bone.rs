@@ -0,0 +1,189 @@
+use gltf::json::Index;
+#[cfg(feature = "rustc_hash")]
+use rustc_hash::FxHashSet as HashSet;
+#[cfg(not(feature = "rustc_hash"))]
+use std::collections::HashSet;
+use std::collections::VecDeque;
+
+use super::{migrate_vector3, MigrationReport};
+use crate::{
+    vrm_0_0::{VRMSecondaryAnimation, VRMSecondaryAnimationSpring},
+    vrmc_spring_bone_1_0::{
+        Collider, ColliderGroup, ColliderShape, ColliderShapeSphere, Spring, SpringBoneJoint,
+        VrmcSpringBoneSchema,
+    },
+};
+
+/// Migrates `secondaryAnimation` of the `VRM` extension of VRM 0.x to the `VRMC_springBone`
+/// extension of VRM 1.0.
+///
+/// Each root bone of a bone group is expanded into chains of joints by walking the node
+/// hierarchy. A chain follows the first child of each node, and the other children start new
+/// chains. Each collider group of VRM 0.x becomes a collider group of VRM 1.0 at the same index.
+pub fn migrate_spring_bone(
+    secondary_animation: &VRMSecondaryAnimation,
+    doc: &gltf::Document,
+    report: &mut MigrationReport,
+) -> VrmcSpringBoneSchema {
+    let mut colliders = Vec::new();
+    let mut collider_groups = Vec::new();
+    for (i, group) in secondary_animation
+        .collider_groups
+        .iter()
+        .flatten()
+        .enumerate()
+    {
+        let pointer = format!("/secondaryAnimation/colliderGroups/{}", i);
+        let mut group_colliders = Vec::new();
+        match group.node {
+            Some(node) => {
+                for collider in group.colliders.iter().flatten() {
+                    let offset = collider.offset.as_ref().map(|offset| {
+                        migrate_vector3(
+                            offset.x.unwrap_or_default(),
+                            offset.y.unwrap_or_default(),
+                            offset.z.unwrap_or_default(),
+                        )
+                    });
+                    group_colliders.push(colliders.len());
+                    colliders.push(Collider {
+                        extensions: None,
+                        extras: None,
+                        node,
+                        shape: ColliderShape {
+                            capsule: None,
+                            extensions: None,
+                            extras: None,
+                            sphere: Some(ColliderShapeSphere {
+                                offset,
+                                radius: collider.radius,
+                            }),
+                            #[cfg(feature = "preserve_unknown_fields")]
+                            unknown_fields: Default::default(),
+                        },
+                        #[cfg(feature = "preserve_unknown_fields")]
+                        unknown_fields: Default::default(),
+                    });
+                }
+            }
+            None => report.note(
+                &pointer,
+                "node is missing; the colliders of the group are dropped",
+            ),
+        }
+        if group_colliders.is_empty() {
+            report.note(pointer, "the collider group has no collider");
+        }
+        collider_groups.push(ColliderGroup {
+            colliders: group_colliders,
+            extensions: None,
+            extras: None,
+            name: None,
+            #[cfg(feature = "preserve_unknown_fields")]
+            unknown_fields: Default::default(),
+        });
+    }
+
+    let mut springs = Vec::new();
+    let mut used: HashSet<usize> = HashSet::default();
+    for (i, group) in secondary_animation.bone_groups.iter().flatten().enumerate() {
+        let pointer = format!("/secondaryAnimation/boneGroups/{}", i);
+        let mut spring_collider_groups = Vec::new();
+        for (j, &collider_group) in group.collider_groups.iter().flatten().enumerate() {
+            if usize::try_from(collider_group).map_or(true, |g| g >= collider_groups.len()) {
+                report.note(
+                    format!("{}/colliderGroups/{}", pointer, j),
+                    format!(
+                        "collider group {} does not exist; the reference is dropped",
+                        collider_group
+                    ),
+                );
+                continue;
+            }
+            spring_collider_groups.push(collider_group);
+        }
+        for (j, root) in group.bones.iter().flatten().enumerate() {
+            let Some(root) = doc.nodes().nth(root.value()) else {
+                report.note(
+                    format!("{}/bones/{}", pointer, j),
+                    format!("node {} does not exist; the bone is dropped", root),
+                );
+                continue;
+            };
+            let mut starts = VecDeque::from([root]);
+            while let Some(start) = starts.pop_front() {
+                let mut joints = Vec::new();
+                let mut node = Some(start);
+                while let Some(current) = node {
+                    if !used.insert(current.index()) {
+                        report.note(
+                            format!("{}/bones/{}", pointer, j),
+                            format!(
+                                "node {} is already a joint of another spring; the chain is cut",
+                                current.index()
+                            ),
+                        );
+                        break;
+                    }
+                    joints.push(migrate_joint(group, current.index()));
+                    let mut children = current.children();
+                    node = children.next();
+                    starts.extend(children);
+                }
+                if joints.is_empty() {
+                    continue;
+                }
+                springs.push(Spring {
+                    center: group.center,
+                    collider_groups: (!spring_collider_groups.is_empty())
+                        .then(|| spring_collider_groups.clone()),
+                    extensions: None,
+                    extras: None,
+                    joints,
+                    name: group.comment.clone(),
+                    #[cfg(feature = "preserve_unknown_fields")]
+                    unknown_fields: Default::default(),
+                });
+            }
+        }
+    }
+    if !springs.is_empty() {
+        report.note(
+            "/secondaryAnimation/boneGroups",
+            "VRM 0.x sways the leaf bones with a virtual tail, which is not supported in VRM 1.0",
+        );
+    }
+
+    VrmcSpringBoneSchema {
+        collider_groups: (!collider_groups.is_empty()).then_some(collider_groups),
+        colliders: (!colliders.is_empty()).then_some(colliders),
+        extensions: None,
+        extras: None,
+        spec_version: "1.0".to_owned(),
+        springs: (!springs.is_empty()).then_some(springs),
+        #[cfg(feature = "preserve_unknown_fields")]
+        unknown_fields: Default::default(),
+    }
+}
+
+/// The parameters of VRM 0.x are shared by the group, so every joint has the same ones.
+fn migrate_joint(group: &VRMSecondaryAnimationSpring, node: usize) -> SpringBoneJoint {
+    SpringBoneJoint {
+        drag_force: group.drag_force,
+        extensions: None,
+        extras: None,
+        gravity_dir: group.gravity_dir.as_ref().map(|dir| {
+            migrate_vector3(
+                dir.x.unwrap_or_default(),
+                dir.y.unwrap_or_default(),
+                dir.z.unwrap_or_default(),
+            )
+        }),
+        gravity_power: group.gravity_power,
+        hit_radius: group.hit_radius,
+        node: Index::new(node as u32),
+        stiffness: group.stiffiness,
+        #[cfg(feature = "preserve_unknown_fields")]
+        unknown_fields: Default::default(),
+    }
+}
@@ -0,0 +1,644 @@
+use gltf::json::Index;
+#[cfg(feature = "rustc_hash")]
+use rustc_hash::FxHashMap as HashMap;
+use std::collections::hash_map::Entry;
+#[cfg(not(feature = "rustc_hash"))]
+use std::collections::HashMap;
+
+use super::{
+    cc_license_url, gamma_to_linear, mesh_nodes, migrate_vector3, name_of, MigrationReport,
+    LINEAR_CURVE,
+};
+use crate::{
+    vrm_0_0::{
+        AllowedUserName, LicenseName, LookAtTypeName, UssageName, VRM0Schema, VRMBlendShape,
+        VRMBlendShapeGroup, VRMFirstPerson, VRMFirstPersonDegreeMap, VRMHumanoid, VRMMeta,
+    },
+    vrmc_vrm_1_0::{
+        AvatarPermissionType, CommercialUsageType, CreditNotationType, Expression,
+        ExpressionPresetName, Expressions, FirstPerson, FirstPersonType, HumanBone, HumanBoneName,
+        HumanBones, Humanoid, LookAt, LookAtRangeMap, LookAtType, MaterialColorBind,
+        MaterialColorType, MeshAnnotation, Meta, ModificationType, MorphTargetBind, Preset,
+        TextureTransformBind, VRMCVrmSchema,
+    },
+};
+
+/// The license of VRM 1.0, which VRM 0.x licenses are migrated to
+const VRM_1_0_LICENSE_URL: &str = "https://vrm.dev/licenses/1.0/";
+
+/// Migrates the `VRM` extension of VRM 0.x to the `VRMC_vrm` extension of VRM 1.0.
+pub fn migrate_vrm0(vrm0: &VRM0Schema, doc: &gltf::Document) -> (VRMCVrmSchema, MigrationReport) {
+    let mut report = MigrationReport::default();
+
+    if vrm0.humanoid.is_none() {
+        report.note("/humanoid", "humanoid is missing");
+    }
+    let humanoid = migrate_humanoid(
+        vrm0.humanoid.as_ref().unwrap_or(&VRMHumanoid::default()),
+        &mut report,
+    );
+
+    if vrm0.meta.is_none() {
+        report.note("/meta", "meta is missing");
+    }
+    let meta = migrate_meta(
+        vrm0.meta.as_ref().unwrap_or(&VRMMeta::default()),
+        doc,
+        &mut report,
+    );
+
+    let expressions = vrm0
+        .blend_shape_master
+        .as_ref()
+        .map(|blend_shape| migrate_expressions(blend_shape, doc, &mut report));
+
+    let (first_person, look_at) = match &vrm0.first_person {
+        Some(first_person) => {
+            let (first_person, look_at) =
+                migrate_first_person(first_person, &humanoid, doc, &mut report);
+            (Some(first_person), Some(look_at))
+        }
+        None => (None, None),
+    };
+
+    let vrmc_vrm = VRMCVrmSchema {
+        expressions,
+        extensions: None,
+        extras: None,
+        first_person,
+        humanoid,
+        look_at,
+        meta,
+        spec_version: "1.0".to_owned(),
+        #[cfg(feature = "preserve_unknown_fields")]
+        unknown_fields: Default::default(),
+    };
+
+    (vrmc_vrm, report)
+}
+
+/// Migrates `humanoid`. The thumb bones are renamed as VRM 1.0 adds the metacarpal.
+pub fn migrate_humanoid(humanoid: &VRMHumanoid, report: &mut MigrationReport) -> Humanoid {
+    let mut human_bones = HashMap::default();
+    for (i, bone) in humanoid.human_bones.iter().flatten().enumerate() {
+        let pointer = format!("/humanoid/humanBones/{}", i);
+        let Some(name) = bone.bone else {
+            report.note(pointer, "bone name is missing; the bone is dropped");
+            continue;
+        };
+        let Some(node) = bone.node else {
+            report.note(pointer, "node is missing; the bone is dropped");
+            continue;
+        };
+        if bone.use_default_values == Some(false) {
+            report.note(
+                &pointer,
+                "Unity's HumanLimit is not supported in VRM 1.0 and dropped",
+            );
+        }
+
+        let name = HumanBoneName::from(name);
+        let human_bone = HumanBone {
+            extensions: None,
+            extras: None,
+            node: Some(node),
+            #[cfg(feature = "preserve_unknown_fields")]
+            unknown_fields: Default::default(),
+        };
+        if human_bones.insert(name, Some(human_bone)).is_some() {
+            report.note(
+                pointer,
+                format!("{} is assigned twice; the last one is used", name_of(&name)),
+            );
+        }
+    }
+
+    if humanoid.arm_stretch.is_some()
+        || humanoid.feet_spacing.is_some()
+        || humanoid.has_translation_do_f.is_some()
+        || humanoid.leg_stretch.is_some()
+        || humanoid.lower_arm_twist.is_some()
+        || humanoid.lower_leg_twist.is_some()
+        || humanoid.upper_arm_twist.is_some()
+        || humanoid.upper_leg_twist.is_some()
+    {
+        report.note(
+            "/humanoid",
+            "Unity's HumanDescription is not supported in VRM 1.0 and dropped",
+        );
+    }
+
+    Humanoid {
+        extensions: None,
+        extras: None,
+        human_bones: HumanBones(human_bones),
+        #[cfg(feature = "preserve_unknown_fields")]
+        unknown_fields: Default::default(),
+    }
+}
+
+/// Migrates `meta`. The license of VRM 0.x is migrated to the VRM 1.0 license, and the original
+/// license document is referred by `otherLicenseUrl`.
+pub fn migrate_meta(meta: &VRMMeta, doc: &gltf::Document, report: &mut MigrationReport) -> Meta {
+    let name = meta.title.clone().unwrap_or_else(|| {
+        report.note("/meta/title", "title is missing; name is left empty");
+        String::new()
+    });
+
+    let authors = match meta.author.as_deref() {
+        Some(author) if !author.is_empty() => vec![author.to_owned()],
+        _ => {
+            report.note(
+                "/meta/author",
+                "author is missing; VRM 1.0 requires at least one author",
+            );
+            Vec::new()
+        }
+    };
+
+    let thumbnail_image =
+        meta.texture
+            .and_then(|texture| match doc.textures().nth(texture.value()) {
+                Some(texture) => Some(Index::new(texture.source().index() as u32)),
+                None => {
+                    report.note(
+                        "/meta/texture",
+                        format!("texture {} does not exist; thumbnail is dropped", texture),
+                    );
+                    None
+                }
+            });
+
+    let mut other_license_url = None;
+    let mut allow_redistribution = None;
+    let mut modification = None;
+    let mut credit_notation = None;
+    let mut non_commercial = false;
+    let cc_license = |name| cc_license_url(name).map(ToOwned::to_owned);
+    match meta.license_name {
+        Some(LicenseName::Cc0) => {
+            other_license_url = cc_license(LicenseName::Cc0);
+            allow_redistribution = Some(true);
+            modification = Some(ModificationType::AllowModificationRedistribution);
+            credit_notation = Some(CreditNotationType::Unnecessary);
+        }
+        Some(LicenseName::CcBy) => {
+            other_license_url = cc_license(LicenseName::CcBy);
+            allow_redistribution = Some(true);
+            modification = Some(ModificationType::AllowModificationRedistribution);
+            credit_notation = Some(CreditNotationType::Required);
+        }
+        Some(LicenseName::CcByNc) => {
+            other_license_url = cc_license(LicenseName::CcByNc);
+            allow_redistribution = Some(true);
+            modification = Some(ModificationType::AllowModificationRedistribution);
+            credit_notation = Some(CreditNotationType::Required);
+            non_commercial = true;
+        }
+        Some(LicenseName::CcByNcNd) => {
+            other_license_url = cc_license(LicenseName::CcByNcNd);
+            allow_redistribution = Some(true);
+            modification = Some(ModificationType::Prohibited);
+            credit_notation = Some(CreditNotationType::Required);
+            non_commercial = true;
+        }
+        Some(LicenseName::CcByNcSa) => {
+            other_license_url = cc_license(LicenseName::CcByNcSa);
+            allow_redistribution = Some(true);
+            modification = Some(ModificationType::AllowModificationRedistribution);
+            credit_notation = Some(CreditNotationType::Required);
+            non_commercial = true;
+            report.note(
+                "/meta/licenseName",
+                "ShareAlike is only described by otherLicenseUrl in VRM 1.0",
+            );
+        }
+        Some(LicenseName::CcByNd) => {
+            other_license_url = cc_license(LicenseName::CcByNd);
+            allow_redistribution = Some(true);
+            modification = Some(ModificationType::Prohibited);
+            credit_notation = Some(CreditNotationType::Required);
+        }
+        Some(LicenseName::CcBySa) => {
+            other_license_url = cc_license(LicenseName::CcBySa);
+            allow_redistribution = Some(true);
+            modification = Some(ModificationType::AllowModificationRedistribution);
+            credit_notation = Some(CreditNotationType::Required);
+            report.note(
+                "/meta/licenseName",
+                "ShareAlike is only described by otherLicenseUrl in VRM 1.0",
+            );
+        }
+        Some(LicenseName::RedistributionProhibited) => {
+            allow_redistribution = Some(false);
+            modification = Some(ModificationType::Prohibited);
+            report.note(
+                "/meta/licenseName",
+                "modification is not defined by Redistribution_Prohibited; it is prohibited",
+            );
+        }
+        Some(LicenseName::Other) => {
+            other_license_url = meta.other_license_url.clone();
+            report.note(
+                "/meta/licenseName",
+                "the license is only described by otherLicenseUrl; \
+                 redistribution and modification are prohibited by default",
+            );
+        }
+        None => {
+            report.note(
+                "/meta/licenseName",
+                "licenseName is missing; the VRM 1.0 defaults are used",
+            );
+        }
+    }
+    if let Some(other_permission_url) = &meta.other_permission_url {
+        if other_license_url.is_none() {
+            other_license_url = Some(other_permission_url.clone());
+            report.note(
+                "/meta/otherPermissionUrl",
+                "otherPermissionUrl is migrated to otherLicenseUrl",
+            );
+        } else {
+            report.note(
+                "/meta/otherPermissionUrl",
+                "otherPermissionUrl has no counterpart in VRM 1.0 and dropped",
+            );
+        }
+    }
+
+    let commercial_usage = match meta.commercial_ussage_name {
+        Some(UssageName::Allow) if non_commercial => {
+            report.note(
+                "/meta/commercialUssageName",
+                "commercial usage is allowed but the license is NonCommercial; \
+                 it is migrated to personalNonProfit",
+            );
+            Some(CommercialUsageType::PersonalNonProfit)
+        }
+        Some(UssageName::Allow) => Some(CommercialUsageType::PersonalProfit),
+        Some(UssageName::Disallow) => Some(CommercialUsageType::PersonalNonProfit),
+        None if non_commercial => Some(CommercialUsageType::PersonalNonProfit),
+        None => None,
+    };
+
+    let avatar_permission = meta.allowed_user_name.map(|name| match name {
+        AllowedUserName::Everyone => AvatarPermissionType::Everyone,
+        AllowedUserName::ExplicitlyLicensedPerson => {
+            AvatarPermissionType::OnlySeparatelyLicensedPerson
+        }
+        AllowedUserName::OnlyAuthor => AvatarPermissionType::OnlyAuthor,
+    });
+
+    Meta {
+        allow_antisocial_or_hate_usage: None,
+        allow_excessively_sexual_usage: meta.sexual_ussage_name.map(is_allowed),
+        allow_excessively_violent_usage: meta.violent_ussage_name.map(is_allowed),
+        allow_political_or_religious_usage: None,
+        allow_redistribution,
+        authors,
+        avatar_permission,
+        commercial_usage,
+        contact_information: meta.contact_information.clone(),
+        copyright_information: None,
+        credit_notation,
+        extensions: None,
+        extras: None,
+        license_url: VRM_1_0_LICENSE_URL.to_owned(),
+        modification,
+        name,
+        other_license_url,
+        references: meta.reference.clone().map(|reference| vec![reference]),
+        third_party_licenses: None,
+        thumbnail_image,
+        version: meta.version.clone(),
+        #[cfg(feature = "preserve_unknown_fields")]
+        unknown_fields: Default::default(),
+    }
+}
+
+/// Migrates `blendShapeMaster`. Blend shape weights are rescaled from 0..100 to 0..1, and each
+/// bind of a mesh is expanded to all the nodes which have the mesh.
+pub fn migrate_expressions(
+    blend_shape: &VRMBlendShape,
+    doc: &gltf::Document,
+    report: &mut MigrationReport,
+) -> Expressions {
+    let mut preset = HashMap::default();
+    let mut custom = HashMap::default();
+    for (i, group) in blend_shape.blend_shape_groups.iter().flatten().enumerate() {
+        let pointer = format!("/blendShapeMaster/blendShapeGroups/{}", i);
+        let expression = migrate_expression(group, doc, &pointer, report);
+        match group
+            .preset_name
+            .and_then(|name| ExpressionPresetName::try_from(name).ok())
+        {
+            Some(name) => match preset.entry(name) {
+                Entry::Occupied(_) => report.note(
+                    pointer,
+                    format!("{} is defined twice; the first one is used", name_of(&name)),
+                ),
+                Entry::Vacant(entry) => {
+                    entry.insert(expression);
+                }
+            },
+            None => match &group.name {
+                Some(name) if !name.is_empty() => match custom.entry(name.clone()) {
+                    Entry::Occupied(_) => report.note(
+                        pointer,
+                        format!("{} is defined twice; the first one is used", name),
+                    ),
+                    Entry::Vacant(entry) => {
+                        entry.insert(expression);
+                    }
+                },
+                _ => report.note(pointer, "name is missing; the expression is dropped"),
+            },
+        }
+    }
+
+    Expressions {
+        custom: (!custom.is_empty()).then_some(custom),
+        extensions: None,
+        extras: None,
+        preset: (!preset.is_empty()).then_some(Preset(preset)),
+        #[cfg(feature = "preserve_unknown_fields")]
+        unknown_fields: Default::default(),
+    }
+}
+
+fn migrate_expression(
+    group: &VRMBlendShapeGroup,
+    doc: &gltf::Document,
+    pointer: &str,
+    report: &mut MigrationReport,
+) -> Expression {
+    let mut morph_target_binds = Vec::new();
+    for (i, bind) in group.binds.iter().flatten().enumerate() {
+        let pointer = format!("{}/binds/{}", pointer, i);
+        let (Some(mesh), Some(index), Some(weight)) = (bind.mesh, bind.index, bind.weight) else {
+            report.note(
+                pointer,
+                "mesh, index or weight is missing; the bind is dropped",
+            );
+            continue;
+        };
+        let Ok(index) = usize::try_from(index) else {
+            report.note(pointer, "index is negative; the bind is dropped");
+            continue;
+        };
+        let nodes = mesh_nodes(doc, mesh.value());
+        if nodes.is_empty() {
+            report.note(
+                pointer,
+                format!("no node has mesh {}; the bind is dropped", mesh),
+            );
+        }
+        morph_target_binds.extend(nodes.into_iter().map(|node| MorphTargetBind {
+            extensions: None,
+            extras: None,
+            index,
+            node: Index::new(node as u32),
+            weight: weight / 100.0,
+            #[cfg(feature = "preserve_unknown_fields")]
+            unknown_fields: Default::default(),
+        }));
+    }
+
+    let mut material_color_binds = Vec::new();
+    let mut texture_transform_binds = Vec::new();
+    for (i, material_value) in group.material_values.iter().flatten().enumerate() {
+        let pointer = format!("{}/materialValues/{}", pointer, i);
+        let (Some(material_name), Some(property_name), Some(target_value)) = (
+            &material_value.material_name,
+            &material_value.property_name,
+            &material_value.target_value,
+        ) else {
+            report.note(
+                pointer,
+                "materialName, propertyName or targetValue is missing; the bind is dropped",
+            );
+            continue;
+        };
+        let Some(material) = doc
+            .materials()
+            .position(|material| material.name() == Some(material_name.as_str()))
+        else {
+            report.note(
+                pointer,
+                format!(
+                    "material {} does not exist; the bind is dropped",
+                    material_name
+                ),
+            );
+            continue;
+        };
+        let [x, y, z, w] = match target_value[..] {
+            [x, y, z, w] => [x, y, z, w],
+            _ => {
+                report.note(pointer, "targetValue is not a vector4; the bind is dropped");
+                continue;
+            }
+        };
+
+        if property_name == "_MainTex_ST" {
+            // NOTE: the origin of UV is bottom-left in Unity and top-left in glTF
+            texture_transform_binds.push(TextureTransformBind {
+                extensions: None,
+                extras: None,
+                material: Index::new(material as u32),
+                offset: Some(vec![z, 1.0 - w - y]),
+                scale: Some(vec![x, y]),
+                #[cfg(feature = "preserve_unknown_fields")]
+                unknown_fields: Default::default(),
+            });
+            continue;
+        }
+
+        let (material_color_bind_type, linear) = match property_name.as_str() {
+            "_Color" => (MaterialColorType::Color, false),
+            "_EmissionColor" => (MaterialColorType::EmissionColor, true),
+            "_OutlineColor" => (MaterialColorType::OutlineColor, false),
+            "_RimColor" => (MaterialColorType::RimColor, false),
+            "_ShadeColor" => (MaterialColorType::ShadeColor, false),
+            _ => {
+                report.note(
+                    pointer,
+                    format!(
+                        "{} is not supported in VRM 1.0; the bind is dropped",
+                        property_name
+                    ),
+                );
+                continue;
+            }
+        };
+        let target_value = if linear {
+            vec![x, y, z, w]
+        } else {
+            vec![
+                gamma_to_linear(x),
+                gamma_to_linear(y),
+                gamma_to_linear(z),
+                w,
+            ]
+        };
+        material_color_binds.push(MaterialColorBind {
+            extensions: None,
+            extras: None,
+            material: Index::new(material as u32),
+            target_value,
+            material_color_bind_type,
+            #[cfg(feature = "preserve_unknown_fields")]
+            unknown_fields: Default::default(),
+        });
+    }
+
+    Expression {
+        extensions: None,
+        extras: None,
+        is_binary: group.is_binary,
+        material_color_binds: (!material_color_binds.is_empty()).then_some(material_color_binds),
+        morph_target_binds: (!morph_target_binds.is_empty()).then_some(morph_target_binds),
+        override_blink: None,
+        override_look_at: None,
+        override_mouth: None,
+        texture_transform_binds: (!texture_transform_binds.is_empty())
+            .then_some(texture_transform_binds),
+        #[cfg(feature = "preserve_unknown_fields")]
+        unknown_fields: Default::default(),
+    }
+}
+
+/// Migrates `firstPerson` to `firstPerson` and `lookAt`. VRM 1.0 always uses the head bone as
+/// the first-person bone, and `humanoid` is the migrated one to find it.
+pub fn migrate_first_person(
+    first_person: &VRMFirstPerson,
+    humanoid: &Humanoid,
+    doc: &gltf::Document,
+    report: &mut MigrationReport,
+) -> (FirstPerson, LookAt) {
+    let head = humanoid
+        .human_bones
+        .0
+        .get(&HumanBoneName::Head)
+        .and_then(|bone| bone.as_ref()?.node);
+    if first_person.first_person_bone.is_some() && first_person.first_person_bone != head {
+        report.note(
+            "/firstPerson/firstPersonBone",
+            "firstPersonBone is not the head; VRM 1.0 always uses the head bone",
+        );
+    }
+
+    let mut mesh_annotations = Vec::new();
+    for (i, annotation) in first_person.mesh_annotations.iter().flatten().enumerate() {
+        let pointer = format!("/firstPerson/meshAnnotations/{}", i);
+        let Some(mesh) = annotation.mesh else {
+            report.note(pointer, "mesh is missing; the annotation is dropped");
+            continue;
+        };
+        let mesh_annotation_type = match annotation.first_person_flag.as_deref() {
+            Some("Auto") => FirstPersonType::Auto,
+            Some("Both") => FirstPersonType::Both,
+            Some("FirstPersonOnly") => FirstPersonType::FirstPersonOnly,
+            Some("ThirdPersonOnly") => FirstPersonType::ThirdPersonOnly,
+            flag => {
+                report.note(
+                    &pointer,
+                    format!("firstPersonFlag {:?} is unknown; auto is used", flag),
+                );
+                FirstPersonType::Auto
+            }
+        };
+        let nodes = mesh_nodes(doc, mesh.value());
+        if nodes.is_empty() {
+            report.note(
+                pointer,
+                format!("no node has mesh {}; the annotation is dropped", mesh),
+            );
+        }
+        mesh_annotations.extend(nodes.into_iter().map(|node| MeshAnnotation {
+            extensions: None,
+            extras: None,
+            node: Some(Index::new(node as u32)),
+            mesh_annotation_type,
+            #[cfg(feature = "preserve_unknown_fields")]
+            unknown_fields: Default::default(),
+        }));
+    }
+
+    let offset_from_head_bone = first_person
+        .first_person_bone_offset
+        .as_ref()
+        .map(|offset| {
+            migrate_vector3(
+                offset.x.unwrap_or_default(),
+                offset.y.unwrap_or_default(),
+                offset.z.unwrap_or_default(),
+            )
+            .to_vec()
+        });
+
+    let mut range_map = |map: &Option<VRMFirstPersonDegreeMap>, pointer: &str| {
+        let map = map.as_ref()?;
+        if matches!(&map.curve, Some(curve) if curve[..] != LINEAR_CURVE[..]) {
+            report.note(
+                pointer,
+                "curve is not supported in VRM 1.0; it is migrated as linear",
+            );
+        }
+        Some(LookAtRangeMap {
+            extensions: None,
+            extras: None,
+            input_max_value: map.x_range,
+            output_scale: map.y_range,
+            #[cfg(feature = "preserve_unknown_fields")]
+            unknown_fields: Default::default(),
+        })
+    };
+    let range_map_horizontal_inner = range_map(
+        &first_person.look_at_horizontal_inner,
+        "/firstPerson/lookAtHorizontalInner/curve",
+    );
+    let range_map_horizontal_outer = range_map(
+        &first_person.look_at_horizontal_outer,
+        "/firstPerson/lookAtHorizontalOuter/curve",
+    );
+    let range_map_vertical_down = range_map(
+        &first_person.look_at_vertical_down,
+        "/firstPerson/lookAtVerticalDown/curve",
+    );
+    let range_map_vertical_up = range_map(
+        &first_person.look_at_vertical_up,
+        "/firstPerson/lookAtVerticalUp/curve",
+    );
+
+    let look_at = LookAt {
+        extensions: None,
+        extras: None,
+        offset_from_head_bone,
+        range_map_horizontal_inner,
+        range_map_horizontal_outer,
+        range_map_vertical_down,
+        range_map_vertical_up,
+        look_at_type: first_person.look_at_type_name.map(|name| match name {
+            LookAtTypeName::BlendShape => LookAtType::Expression,
+            LookAtTypeName::Bone => LookAtType::Bone,
+        }),
+        #[cfg(feature = "preserve_unknown_fields")]
+        unknown_fields: Default::default(),
+    };
+
+    let first_person = FirstPerson {
+        extensions: None,
+        extras: None,
+        mesh_annotations: (!mesh_annotations.is_empty()).then_some(mesh_annotations),
+        #[cfg(feature = "preserve_unknown_fields")]
+        unknown_fields: Default::default(),
+    };
+
+    (first_person, look_at)
+}
+
+fn is_allowed(name: UssageName) -> bool {
+    matches!(name, UssageName::Allow)
+}
@@ -1,8 +1,11 @@
-/// This mod handles common issues when using VRMs.
+//! This mod handles common issues when using VRMs.
 
 #[cfg(feature = "rustc_hash")]
 use rustc_hash::FxHashMap as HashMap;
-use serde::{Deserialize, Deserializer};
+use serde::{
+    de::{DeserializeOwned, DeserializeSeed, IntoDeserializer, MapAccess, Visitor},
+    Deserialize, Deserializer,
+};
 #[cfg(not(feature = "rustc_hash"))]
 use std::collections::HashMap;
 
@@ -61,3 +64,107 @@ where
         map.into_iter().filter_map(|(k, v)| v.map(|v| (k, v))),
     )))
 }
+
+/// Deserializes an extension stored in the `extensions` field of the VRM data structures. The
+/// JSON pointer of the error is relative to the data structure.
+pub(crate) fn deserialize_extension<T>(
+    extensions: Option<&HashMap<String, HashMap<String, Option<serde_json::Value>>>>,
+    name: &str,
+) -> Option<Result<T, crate::Error>>
+where
+    T: DeserializeOwned,
+{
+    let extension = extensions?.get(name)?;
+    let value = serde_json::Value::Object(serde_json::Map::from_iter(
+        extension
+            .iter()
+            .map(|(k, v)| (k.clone(), v.clone().unwrap_or(serde_json::Value::Null))),
+    ));
+    Some(crate::error::from_value_at(
+        &value,
+        &format!("/extensions/{}", name),
+    ))
+}
+
+// NOTE: serde_path_to_error can't capture map keys deserialized as enums. Deserialize the keys
+// as strings first so that errors point at e.g. `/humanoid/humanBones/leftHand/node`
+pub(crate) fn deserialize_map_with_str_keys<'de, D, K, V>(
+    deserializer: D,
+) -> Result<HashMap<K, V>, D::Error>
+where
+    K: Eq + std::hash::Hash + Deserialize<'de>,
+    V: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    HashMap::deserialize(StrKeys(deserializer))
+}
+
+/// Wraps a deserializer of a map, its visitor and its map access so that the keys are
+/// deserialized from strings. The map itself is built by the `Deserialize` impl of `HashMap`.
+struct StrKeys<T>(T);
+
+impl<'de, D> Deserializer<'de> for StrKeys<D>
+where
+    D: Deserializer<'de>,
+{
+    type Error = D::Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.0.deserialize_map(StrKeys(visitor))
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+impl<'de, V> Visitor<'de> for StrKeys<V>
+where
+    V: Visitor<'de>,
+{
+    type Value = V::Value;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.0.expecting(formatter)
+    }
+
+    fn visit_map<A>(self, access: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        self.0.visit_map(StrKeys(access))
+    }
+}
+
+impl<'de, A> MapAccess<'de> for StrKeys<A>
+where
+    A: MapAccess<'de>,
+{
+    type Error = A::Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        match self.0.next_key::<String>()? {
+            Some(key) => seed.deserialize(key.into_deserializer()).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Self::Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        self.0.next_value_seed(seed)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.0.size_hint()
+    }
+}
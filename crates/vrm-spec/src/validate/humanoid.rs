@@ -0,0 +1,318 @@
+use std::fmt;
+
+#[cfg(feature = "rustc_hash")]
+use rustc_hash::FxHashMap as HashMap;
+use serde::Serialize;
+#[cfg(not(feature = "rustc_hash"))]
+use std::collections::HashMap;
+
+use super::node_parents;
+use crate::{
+    migrate::name_of,
+    vrm_0_0::{Bone, VRMHumanoid},
+    vrmc_vrm_1_0::{HumanBoneGroup, HumanBoneName, Humanoid},
+};
+
+/// A violation of the humanoid rules of `VRMC_vrm` 1.0
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HumanoidError {
+    /// A required bone is not in `humanBones`
+    MissingRequiredBone { bone: HumanBoneName },
+
+    /// A bone in `humanBones` has no node
+    MissingNode { bone: HumanBoneName },
+
+    /// An optional bone exists without the bone which it depends on, e.g. `upperChest` without
+    /// `chest`
+    MissingDependency {
+        bone: HumanBoneName,
+        dependency: HumanBoneName,
+    },
+
+    /// A node is assigned to the bone and another bone
+    DuplicateNode {
+        bone: HumanBoneName,
+        node: usize,
+        other: HumanBoneName,
+    },
+}
+
+impl HumanoidError {
+    /// The bone which violates the rule
+    pub fn bone(&self) -> HumanBoneName {
+        match self {
+            HumanoidError::MissingRequiredBone { bone }
+            | HumanoidError::MissingNode { bone }
+            | HumanoidError::MissingDependency { bone, .. }
+            | HumanoidError::DuplicateNode { bone, .. } => *bone,
+        }
+    }
+
+    /// Stable identifier of the kind of the error, e.g. `HUMANOID_MISSING_REQUIRED_BONE`
+    pub fn code(&self) -> &'static str {
+        match self {
+            HumanoidError::MissingRequiredBone { .. } => "HUMANOID_MISSING_REQUIRED_BONE",
+            HumanoidError::MissingNode { .. } => "HUMANOID_MISSING_NODE",
+            HumanoidError::MissingDependency { .. } => "HUMANOID_MISSING_DEPENDENCY",
+            HumanoidError::DuplicateNode { .. } => "HUMANOID_DUPLICATE_NODE",
+        }
+    }
+}
+
+impl fmt::Display for HumanoidError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HumanoidError::MissingRequiredBone { bone } => {
+                write!(f, "required bone '{}' is missing", name_of(bone))
+            }
+            HumanoidError::MissingNode { bone } => {
+                write!(f, "bone '{}' has no node", name_of(bone))
+            }
+            HumanoidError::MissingDependency { bone, dependency } => write!(
+                f,
+                "bone '{}' requires bone '{}'",
+                name_of(bone),
+                name_of(dependency)
+            ),
+            HumanoidError::DuplicateNode { bone, node, other } => write!(
+                f,
+                "node {} of bone '{}' is also assigned to bone '{}'",
+                node,
+                name_of(bone),
+                name_of(other)
+            ),
+        }
+    }
+}
+
+impl std::error::Error for HumanoidError {}
+
+/// Validates `humanoid` against the rules of `VRMC_vrm` 1.0:
+///
+/// - the required bones exist
+/// - optional bones exist together with the bones which they depend on
+/// - each node is assigned to one bone at most
+///
+/// Bones whose value is `null` are treated as missing. Returns an empty `Vec` if `humanoid` is
+/// valid.
+pub fn validate_humanoid(humanoid: &Humanoid) -> Vec<HumanoidError> {
+    let human_bones = &humanoid.human_bones.0;
+    let exists = |bone: &HumanBoneName| human_bones.get(bone).map_or(false, Option::is_some);
+
+    let mut errors = Vec::new();
+    for bone in HumanBoneName::ALL {
+        if bone.is_required() && !exists(&bone) {
+            errors.push(HumanoidError::MissingRequiredBone { bone });
+        }
+    }
+    for bone in HumanBoneName::ALL {
+        let Some(dependency) = dependency(bone) else {
+            continue;
+        };
+        if exists(&bone) && !exists(&dependency) {
+            errors.push(HumanoidError::MissingDependency { bone, dependency });
+        }
+    }
+
+    // NOTE: sort the bones for a stable order as they come from a map
+    let mut bones: Vec<_> = human_bones
+        .iter()
+        .filter_map(|(&bone, human_bone)| Some((bone, human_bone.as_ref()?.node)))
+        .collect();
+    bones.sort_unstable_by_key(|&(bone, _)| bone as u8);
+    for &(bone, node) in &bones {
+        if node.is_none() {
+            errors.push(HumanoidError::MissingNode { bone });
+        }
+    }
+
+    let mut nodes: Vec<_> = bones
+        .iter()
+        .filter_map(|&(bone, node)| Some((node?.value(), bone)))
+        .collect();
+    nodes.sort_by_key(|&(node, _)| node);
+    for pair in nodes.windows(2) {
+        let [(other_node, other), (node, bone)] = *pair else {
+            unreachable!("windows of 2");
+        };
+        if node == other_node {
+            errors.push(HumanoidError::DuplicateNode { bone, node, other });
+        }
+    }
+    errors
+}
+
+/// A human bone whose node doesn't descend from the node of its parent bone
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HierarchyError<B> {
+    /// The bone which violates the hierarchy
+    pub bone: B,
+
+    /// The node of `bone`
+    pub node: usize,
+
+    /// The name of `node`
+    pub node_name: Option<String>,
+
+    /// The nearest ancestor of `bone` in the humanoid hierarchy which exists, e.g. `leftUpperArm`
+    /// for `leftLowerArm`
+    pub parent: B,
+
+    /// The node of `parent`
+    pub parent_node: usize,
+
+    /// The name of `parent_node`
+    pub parent_node_name: Option<String>,
+}
+
+impl<B> HierarchyError<B> {
+    /// Stable identifier of the kind of the error
+    pub fn code(&self) -> &'static str {
+        "HUMANOID_NOT_DESCENDANT"
+    }
+}
+
+impl<B> fmt::Display for HierarchyError<B>
+where
+    B: Serialize,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} of bone '{}' is not a descendant of {} of bone '{}'",
+            describe_node(self.node, self.node_name.as_deref()),
+            name_of(&self.bone),
+            describe_node(self.parent_node, self.parent_node_name.as_deref()),
+            name_of(&self.parent),
+        )
+    }
+}
+
+impl<B> std::error::Error for HierarchyError<B> where B: fmt::Debug + Serialize {}
+
+/// Validates that the node of each bone of `humanoid` descends from the node of its parent bone
+/// in `doc`. If the parent bone is optional and missing, the nearest ancestor bone which exists is
+/// used instead, e.g. `spine` for `neck` without `chest` and `upperChest`.
+///
+/// Bones whose node doesn't exist in `doc` are skipped.
+pub fn validate_humanoid_hierarchy(
+    humanoid: &Humanoid,
+    doc: &gltf::Document,
+) -> Vec<HierarchyError<HumanBoneName>> {
+    let bones = humanoid
+        .human_bones
+        .0
+        .iter()
+        .filter_map(|(&bone, human_bone)| {
+            let node = human_bone.as_ref()?.node?;
+            Some((bone, (bone, node.value())))
+        })
+        .collect();
+    validate_hierarchy(bones, doc)
+}
+
+/// Same as [`validate_humanoid_hierarchy`], but for the humanoid of VRM 0.x. The hierarchy of
+/// VRM 1.0 is used, where `thumbProximal` and `thumbIntermediate` of VRM 0.x correspond to
+/// `thumbMetacarpal` and `thumbProximal`.
+///
+/// The first one is used if a bone appears more than once.
+pub fn validate_vrm0_humanoid_hierarchy(
+    humanoid: &VRMHumanoid,
+    doc: &gltf::Document,
+) -> Vec<HierarchyError<Bone>> {
+    let mut bones = HashMap::default();
+    for human_bone in humanoid.human_bones.iter().flatten() {
+        if let (Some(bone), Some(node)) = (human_bone.bone, human_bone.node) {
+            bones
+                .entry(HumanBoneName::from(bone))
+                .or_insert((bone, node.value()));
+        }
+    }
+    validate_hierarchy(bones, doc)
+}
+
+fn validate_hierarchy<B>(
+    bones: HashMap<HumanBoneName, (B, usize)>,
+    doc: &gltf::Document,
+) -> Vec<HierarchyError<B>>
+where
+    B: Copy,
+{
+    let parents = node_parents(doc);
+    let is_descendant = |node: usize, ancestor: usize| {
+        // NOTE: limit the steps in case the nodes have a cycle
+        let mut current = node;
+        for _ in 0..parents.len() {
+            match parents[current] {
+                Some(parent) if parent == ancestor => return true,
+                Some(parent) => current = parent,
+                None => return false,
+            }
+        }
+        false
+    };
+    let node_name = |node: usize| {
+        doc.nodes()
+            .nth(node)
+            .and_then(|node| node.name().map(str::to_owned))
+    };
+
+    // NOTE: sort the bones for a stable order as they come from a map
+    let mut sorted: Vec<_> = bones.iter().collect();
+    sorted.sort_unstable_by_key(|&(&name, _)| name as u8);
+
+    let mut errors = Vec::new();
+    for (&name, &(bone, node)) in sorted {
+        let mut parent_name = name.parent();
+        let parent = loop {
+            match parent_name {
+                Some(name) => match bones.get(&name) {
+                    Some(parent) => break Some(*parent),
+                    None => parent_name = name.parent(),
+                },
+                None => break None,
+            }
+        };
+        let Some((parent, parent_node)) = parent else {
+            continue;
+        };
+        if node >= parents.len() || parent_node >= parents.len() {
+            continue;
+        }
+        if !is_descendant(node, parent_node) {
+            errors.push(HierarchyError {
+                bone,
+                node,
+                node_name: node_name(node),
+                parent,
+                parent_node,
+                parent_node_name: node_name(parent_node),
+            });
+        }
+    }
+    errors
+}
+
+fn describe_node(node: usize, name: Option<&str>) -> String {
+    match name {
+        Some(name) => format!("node {} '{}'", node, name),
+        None => format!("node {}", node),
+    }
+}
+
+/// Returns the optional bone which `bone` requires, e.g. `chest` for `upperChest`
+fn dependency(bone: HumanBoneName) -> Option<HumanBoneName> {
+    if bone == HumanBoneName::UpperChest {
+        return Some(HumanBoneName::Chest);
+    }
+    // NOTE: a finger bone requires the finger bones closer to the hand
+    let parent = bone.parent()?;
+    match bone.group() {
+        HumanBoneGroup::LeftFingers | HumanBoneGroup::RightFingers
+            if parent.group() == bone.group() =>
+        {
+            Some(parent)
+        }
+        _ => None,
+    }
+}
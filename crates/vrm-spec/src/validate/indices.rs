@@ -0,0 +1,406 @@
+use std::fmt;
+
+use super::sorted_expressions;
+use crate::{
+    document::Vrm1Extensions,
+    error::push_pointer_token,
+    migrate::name_of,
+    vrm_0_0::{VRM0Schema, VRM},
+    vrmc_materials_mtoon_1_0::VRMC_MATERIALS_MTOON,
+    vrmc_node_constraint_1_0::VRMC_NODE_CONSTRAINT,
+    vrmc_spring_bone_1_0::VRMC_SPRING_BONE,
+    vrmc_vrm_1_0::VRMC_VRM,
+};
+
+/// A reference to an element of a glTF array which doesn't exist
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexError {
+    /// JSON pointer to the reference in the glTF JSON, e.g.
+    /// `/extensions/VRMC_springBone/springs/0/joints/1/node`
+    pub pointer: String,
+
+    /// The referenced index
+    pub index: i64,
+
+    /// JSON pointer to the referenced array in the glTF JSON, e.g. `/nodes` or
+    /// `/extensions/VRMC_springBone/colliders`
+    pub target: String,
+
+    /// The length of `target`
+    pub len: usize,
+}
+
+impl IndexError {
+    /// Stable identifier of the kind of the error
+    pub fn code(&self) -> &'static str {
+        "INDEX_OUT_OF_RANGE"
+    }
+}
+
+impl fmt::Display for IndexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "'{}' refers to index {} of '{}', which has {} elements",
+            self.pointer, self.index, self.target, self.len
+        )
+    }
+}
+
+impl std::error::Error for IndexError {}
+
+/// Collects references out of the bounds of their arrays
+struct Checker {
+    errors: Vec<IndexError>,
+}
+
+impl Checker {
+    fn check(&mut self, pointer: String, index: i64, target: &str, len: usize) {
+        if usize::try_from(index).map_or(true, |index| index >= len) {
+            self.errors.push(IndexError {
+                pointer,
+                index,
+                target: target.to_owned(),
+                len,
+            });
+        }
+    }
+
+    fn check_usize(&mut self, pointer: String, index: usize, target: &str, len: usize) {
+        if index >= len {
+            self.errors.push(IndexError {
+                pointer,
+                index: index as i64,
+                target: target.to_owned(),
+                len,
+            });
+        }
+    }
+}
+
+/// Validates that every index of glTF nodes, meshes, materials, textures and images in the VRM 1.0
+/// extensions refers to an element of `doc`. The indices of colliders in collider groups and of
+/// collider groups in springs are validated against `VRMC_springBone` as well.
+///
+/// Returns an empty `Vec` if all the indices are valid.
+pub fn validate_vrm1_indices(vrm1: &Vrm1Extensions, doc: &gltf::Document) -> Vec<IndexError> {
+    let json = doc.as_json();
+    let nodes = json.nodes.len();
+    let mut checker = Checker { errors: Vec::new() };
+
+    // VRMC_vrm
+    let vrmc_vrm = &vrm1.vrmc_vrm;
+    let pointer = format!("/extensions/{}", VRMC_VRM);
+    let mut human_bones: Vec<_> = vrmc_vrm.humanoid.human_bones.0.iter().collect();
+    human_bones.sort_by_key(|(&bone, _)| bone as u8);
+    for (bone, human_bone) in human_bones {
+        if let Some(node) = human_bone.as_ref().and_then(|human_bone| human_bone.node) {
+            let mut pointer = format!("{}/humanoid/humanBones", pointer);
+            push_pointer_token(&mut pointer, &name_of(bone));
+            checker.check_usize(format!("{}/node", pointer), node.value(), "/nodes", nodes);
+        }
+    }
+    if let Some(image) = vrmc_vrm.meta.thumbnail_image {
+        checker.check_usize(
+            format!("{}/meta/thumbnailImage", pointer),
+            image.value(),
+            "/images",
+            json.images.len(),
+        );
+    }
+    if let Some(expressions) = &vrmc_vrm.expressions {
+        for (_, pointer, expression) in sorted_expressions(expressions, &pointer) {
+            for (i, bind) in expression.morph_target_binds.iter().flatten().enumerate() {
+                checker.check_usize(
+                    format!("{}/morphTargetBinds/{}/node", pointer, i),
+                    bind.node.value(),
+                    "/nodes",
+                    nodes,
+                );
+            }
+            for (i, bind) in expression.material_color_binds.iter().flatten().enumerate() {
+                checker.check_usize(
+                    format!("{}/materialColorBinds/{}/material", pointer, i),
+                    bind.material.value(),
+                    "/materials",
+                    json.materials.len(),
+                );
+            }
+            for (i, bind) in expression
+                .texture_transform_binds
+                .iter()
+                .flatten()
+                .enumerate()
+            {
+                checker.check_usize(
+                    format!("{}/textureTransformBinds/{}/material", pointer, i),
+                    bind.material.value(),
+                    "/materials",
+                    json.materials.len(),
+                );
+            }
+        }
+    }
+    if let Some(first_person) = &vrmc_vrm.first_person {
+        for (i, annotation) in first_person.mesh_annotations.iter().flatten().enumerate() {
+            if let Some(node) = annotation.node {
+                checker.check_usize(
+                    format!("{}/firstPerson/meshAnnotations/{}/node", pointer, i),
+                    node.value(),
+                    "/nodes",
+                    nodes,
+                );
+            }
+        }
+    }
+
+    // VRMC_springBone
+    if let Some(spring_bone) = &vrm1.vrmc_spring_bone {
+        let pointer = format!("/extensions/{}", VRMC_SPRING_BONE);
+        let colliders = spring_bone.colliders.as_deref().unwrap_or_default();
+        let collider_groups = spring_bone.collider_groups.as_deref().unwrap_or_default();
+        for (i, collider) in colliders.iter().enumerate() {
+            checker.check_usize(
+                format!("{}/colliders/{}/node", pointer, i),
+                collider.node.value(),
+                "/nodes",
+                nodes,
+            );
+        }
+        let target = format!("{}/colliders", pointer);
+        for (i, group) in collider_groups.iter().enumerate() {
+            for (j, &collider) in group.colliders.iter().enumerate() {
+                checker.check_usize(
+                    format!("{}/colliderGroups/{}/colliders/{}", pointer, i, j),
+                    collider,
+                    &target,
+                    colliders.len(),
+                );
+            }
+        }
+        let target = format!("{}/colliderGroups", pointer);
+        for (i, spring) in spring_bone.springs.iter().flatten().enumerate() {
+            let pointer = format!("{}/springs/{}", pointer, i);
+            for (j, joint) in spring.joints.iter().enumerate() {
+                checker.check_usize(
+                    format!("{}/joints/{}/node", pointer, j),
+                    joint.node.value(),
+                    "/nodes",
+                    nodes,
+                );
+            }
+            if let Some(center) = spring.center {
+                checker.check_usize(
+                    format!("{}/center", pointer),
+                    center.value(),
+                    "/nodes",
+                    nodes,
+                );
+            }
+            for (j, &group) in spring.collider_groups.iter().flatten().enumerate() {
+                checker.check(
+                    format!("{}/colliderGroups/{}", pointer, j),
+                    group,
+                    &target,
+                    collider_groups.len(),
+                );
+            }
+        }
+    }
+
+    // VRMC_materials_mtoon
+    for (i, mtoon) in vrm1.vrmc_materials_mtoon.iter().enumerate() {
+        let Some(mtoon) = mtoon else {
+            continue;
+        };
+        let pointer = format!("/materials/{}/extensions/{}", i, VRMC_MATERIALS_MTOON);
+        let textures = [
+            ("matcapTexture", &mtoon.matcap_texture),
+            (
+                "outlineWidthMultiplyTexture",
+                &mtoon.outline_width_multiply_texture,
+            ),
+            ("rimMultiplyTexture", &mtoon.rim_multiply_texture),
+            ("shadeMultiplyTexture", &mtoon.shade_multiply_texture),
+            ("uvAnimationMaskTexture", &mtoon.uv_animation_mask_texture),
+        ];
+        let textures = textures
+            .into_iter()
+            .filter_map(|(name, info)| info.as_ref().map(|info| (name, info.index)))
+            .chain(
+                mtoon
+                    .shading_shift_texture
+                    .as_ref()
+                    .map(|info| ("shadingShiftTexture", info.index)),
+            );
+        for (name, index) in textures {
+            checker.check_usize(
+                format!("{}/{}/index", pointer, name),
+                index.value(),
+                "/textures",
+                json.textures.len(),
+            );
+        }
+    }
+
+    // VRMC_node_constraint
+    for (i, node_constraint) in vrm1.vrmc_node_constraints.iter().enumerate() {
+        let Some(node_constraint) = node_constraint else {
+            continue;
+        };
+        let pointer = format!(
+            "/nodes/{}/extensions/{}/constraint",
+            i, VRMC_NODE_CONSTRAINT
+        );
+        let constraint = &node_constraint.constraint;
+        let sources = [
+            ("aim", constraint.aim.as_ref().map(|aim| aim.source)),
+            ("roll", constraint.roll.as_ref().map(|roll| roll.source)),
+            (
+                "rotation",
+                constraint.rotation.as_ref().map(|rotation| rotation.source),
+            ),
+        ];
+        for (name, source) in sources {
+            if let Some(source) = source {
+                checker.check_usize(
+                    format!("{}/{}/source", pointer, name),
+                    source.value(),
+                    "/nodes",
+                    nodes,
+                );
+            }
+        }
+    }
+
+    checker.errors
+}
+
+/// Validates that every index of glTF nodes, meshes and textures in the `VRM` extension of
+/// VRM 0.x refers to an element of `doc`. The indices of collider groups in bone groups are
+/// validated against `secondaryAnimation` as well.
+///
+/// Returns an empty `Vec` if all the indices are valid.
+pub fn validate_vrm0_indices(vrm0: &VRM0Schema, doc: &gltf::Document) -> Vec<IndexError> {
+    let json = doc.as_json();
+    let nodes = json.nodes.len();
+    let meshes = json.meshes.len();
+    let textures = json.textures.len();
+    let mut checker = Checker { errors: Vec::new() };
+    let pointer = format!("/extensions/{}", VRM);
+
+    if let Some(humanoid) = &vrm0.humanoid {
+        for (i, human_bone) in humanoid.human_bones.iter().flatten().enumerate() {
+            if let Some(node) = human_bone.node {
+                checker.check_usize(
+                    format!("{}/humanoid/humanBones/{}/node", pointer, i),
+                    node.value(),
+                    "/nodes",
+                    nodes,
+                );
+            }
+        }
+    }
+    if let Some(texture) = vrm0.meta.as_ref().and_then(|meta| meta.texture) {
+        checker.check_usize(
+            format!("{}/meta/texture", pointer),
+            texture.value(),
+            "/textures",
+            textures,
+        );
+    }
+    if let Some(blend_shape) = &vrm0.blend_shape_master {
+        for (i, group) in blend_shape.blend_shape_groups.iter().flatten().enumerate() {
+            for (j, bind) in group.binds.iter().flatten().enumerate() {
+                if let Some(mesh) = bind.mesh {
+                    checker.check_usize(
+                        format!(
+                            "{}/blendShapeMaster/blendShapeGroups/{}/binds/{}/mesh",
+                            pointer, i, j
+                        ),
+                        mesh.value(),
+                        "/meshes",
+                        meshes,
+                    );
+                }
+            }
+        }
+    }
+    if let Some(first_person) = &vrm0.first_person {
+        if let Some(node) = first_person.first_person_bone {
+            checker.check_usize(
+                format!("{}/firstPerson/firstPersonBone", pointer),
+                node.value(),
+                "/nodes",
+                nodes,
+            );
+        }
+        for (i, annotation) in first_person.mesh_annotations.iter().flatten().enumerate() {
+            if let Some(mesh) = annotation.mesh {
+                checker.check_usize(
+                    format!("{}/firstPerson/meshAnnotations/{}/mesh", pointer, i),
+                    mesh.value(),
+                    "/meshes",
+                    meshes,
+                );
+            }
+        }
+    }
+    if let Some(secondary_animation) = &vrm0.secondary_animation {
+        let pointer = format!("{}/secondaryAnimation", pointer);
+        let collider_groups = secondary_animation
+            .collider_groups
+            .as_deref()
+            .unwrap_or_default();
+        for (i, group) in collider_groups.iter().enumerate() {
+            if let Some(node) = group.node {
+                checker.check_usize(
+                    format!("{}/colliderGroups/{}/node", pointer, i),
+                    node.value(),
+                    "/nodes",
+                    nodes,
+                );
+            }
+        }
+        let target = format!("{}/colliderGroups", pointer);
+        for (i, spring) in secondary_animation.bone_groups.iter().flatten().enumerate() {
+            let pointer = format!("{}/boneGroups/{}", pointer, i);
+            for (j, bone) in spring.bones.iter().flatten().enumerate() {
+                checker.check_usize(
+                    format!("{}/bones/{}", pointer, j),
+                    bone.value(),
+                    "/nodes",
+                    nodes,
+                );
+            }
+            if let Some(center) = spring.center {
+                checker.check_usize(
+                    format!("{}/center", pointer),
+                    center.value(),
+                    "/nodes",
+                    nodes,
+                );
+            }
+            for (j, &group) in spring.collider_groups.iter().flatten().enumerate() {
+                checker.check(
+                    format!("{}/colliderGroups/{}", pointer, j),
+                    group,
+                    &target,
+                    collider_groups.len(),
+                );
+            }
+        }
+    }
+    for (i, material) in vrm0.material_properties.iter().flatten().enumerate() {
+        // NOTE: sort by name to make the output stable
+        let mut texture_properties: Vec<_> = material.texture_properties.iter().flatten().collect();
+        texture_properties.sort_by(|a, b| a.0.cmp(b.0));
+        for (name, texture) in texture_properties {
+            let mut pointer = format!("{}/materialProperties/{}/textureProperties", pointer, i);
+            push_pointer_token(&mut pointer, name);
+            checker.check_usize(pointer, texture.value(), "/textures", textures);
+        }
+    }
+
+    checker.errors
+}
@@ -0,0 +1,106 @@
+//! Validation of VRM data structures against the rules of the specifications which cannot be
+//! expressed by the types, e.g. required humanoid bones.
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! use vrm_spec::{document::VrmDocument, validate};
+//!
+//! let vrm = VrmDocument::from_path("model.vrm").expect("ok");
+//! let report = validate::validate_document(&vrm);
+//! for message in &report.messages {
+//!     println!("{} at {}: {}", message.code, message.pointer, message.message);
+//! }
+//! println!("{}", serde_json::to_string_pretty(&report).expect("ok"));
+//! ```
+//!
+//! Each validator is available on its own as well, e.g. [`validate_humanoid`].
+
+mod humanoid;
+mod indices;
+mod morph_target;
+mod report;
+mod spring_bone;
+mod validated_humanoid;
+
+pub use humanoid::{
+    validate_humanoid, validate_humanoid_hierarchy, validate_vrm0_humanoid_hierarchy,
+    HierarchyError, HumanoidError,
+};
+pub use indices::{validate_vrm0_indices, validate_vrm1_indices, IndexError};
+pub use morph_target::{
+    validate_morph_target_binds, validate_vrm0_morph_target_binds, MorphTargetBindError,
+    MorphTargetBindErrorKind,
+};
+pub use report::{
+    validate_document, validate_vrm0, validate_vrm1, ValidationMessage, ValidationReport,
+};
+pub use spring_bone::{validate_spring_bone, SpringBoneIssue, SpringBoneIssueKind};
+pub use validated_humanoid::ValidatedHumanoid;
+
+use serde::Serialize;
+
+use crate::{
+    error::push_pointer_token,
+    migrate::name_of,
+    vrmc_vrm_1_0::{Expression, Expressions},
+};
+
+/// How serious a problem is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Severity {
+    /// The data violates the specification
+    Error,
+
+    /// The data is allowed but likely unintended, e.g. a parameter which implementations clamp
+    Warning,
+
+    /// The data is valid, but worth knowing
+    Info,
+}
+
+/// Returns the name, the JSON pointer and the expression of each expression under `pointer`, which
+/// is the pointer to `VRMC_vrm`. Presets come first, and each group is sorted by name to make the
+/// output stable.
+fn sorted_expressions<'a>(
+    expressions: &'a Expressions,
+    pointer: &str,
+) -> Vec<(String, String, &'a Expression)> {
+    let mut preset: Vec<_> = expressions
+        .preset
+        .iter()
+        .flat_map(|preset| &preset.0)
+        .map(|(name, expression)| (name_of(name), expression))
+        .collect();
+    preset.sort_by(|a, b| a.0.cmp(&b.0));
+    let mut custom: Vec<_> = expressions
+        .custom
+        .iter()
+        .flatten()
+        .map(|(name, expression)| (name.clone(), expression))
+        .collect();
+    custom.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let preset = preset.into_iter().map(|entry| ("preset", entry));
+    let custom = custom.into_iter().map(|entry| ("custom", entry));
+    preset
+        .chain(custom)
+        .map(|(group, (name, expression))| {
+            let mut pointer = format!("{}/expressions/{}", pointer, group);
+            push_pointer_token(&mut pointer, &name);
+            (name, pointer, expression)
+        })
+        .collect()
+}
+
+/// Returns the parent of each node of `doc`
+fn node_parents(doc: &gltf::Document) -> Vec<Option<usize>> {
+    let mut parents = vec![None; doc.nodes().len()];
+    for node in doc.nodes() {
+        for child in node.children() {
+            parents[child.index()] = Some(node.index());
+        }
+    }
+    parents
+}
@@ -0,0 +1,234 @@
+use std::fmt;
+
+use super::sorted_expressions;
+use crate::{
+    migrate::name_of,
+    vrm_0_0::{VRMBlendShape, VRMBlendShapeBind, VRM},
+    vrmc_vrm_1_0::{Expressions, VRMC_VRM},
+};
+
+/// A morph target bind of an expression which doesn't fit the referenced mesh
+#[derive(Debug, Clone, PartialEq)]
+pub struct MorphTargetBindError {
+    /// The name of the expression, which is the preset name or the custom name for VRM 1.0 and
+    /// the name of the blend shape group for VRM 0.x
+    pub expression: String,
+
+    /// JSON pointer to the bind in the glTF JSON, e.g.
+    /// `/extensions/VRMC_vrm/expressions/preset/happy/morphTargetBinds/0`
+    pub pointer: String,
+
+    pub kind: MorphTargetBindErrorKind,
+}
+
+/// The reason of a [`MorphTargetBindError`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum MorphTargetBindErrorKind {
+    /// The node of the bind has no mesh
+    MissingMesh { node: usize },
+
+    /// `index` of the bind is not below the number of morph targets of the mesh
+    TargetOutOfRange {
+        mesh: usize,
+        index: i64,
+        count: usize,
+    },
+
+    /// `weight` of the bind is not in `0..=max`
+    WeightOutOfRange { weight: f64, max: f64 },
+
+    /// The bind targets the same morph target as the bind `first` of the expression
+    DuplicateTarget { first: usize },
+}
+
+impl MorphTargetBindError {
+    /// Stable identifier of the kind of the error, e.g. `MORPH_TARGET_BIND_MISSING_MESH`
+    pub fn code(&self) -> &'static str {
+        match self.kind {
+            MorphTargetBindErrorKind::MissingMesh { .. } => "MORPH_TARGET_BIND_MISSING_MESH",
+            MorphTargetBindErrorKind::TargetOutOfRange { .. } => {
+                "MORPH_TARGET_BIND_TARGET_OUT_OF_RANGE"
+            }
+            MorphTargetBindErrorKind::WeightOutOfRange { .. } => {
+                "MORPH_TARGET_BIND_WEIGHT_OUT_OF_RANGE"
+            }
+            MorphTargetBindErrorKind::DuplicateTarget { .. } => {
+                "MORPH_TARGET_BIND_DUPLICATE_TARGET"
+            }
+        }
+    }
+}
+
+impl fmt::Display for MorphTargetBindError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "expression '{}': ", self.expression)?;
+        match self.kind {
+            MorphTargetBindErrorKind::MissingMesh { node } => {
+                write!(f, "node {} has no mesh", node)
+            }
+            MorphTargetBindErrorKind::TargetOutOfRange { mesh, index, count } => write!(
+                f,
+                "morph target {} is out of range as mesh {} has {} morph targets",
+                index, mesh, count
+            ),
+            MorphTargetBindErrorKind::WeightOutOfRange { weight, max } => {
+                write!(f, "weight {} is out of range 0..={}", weight, max)
+            }
+            MorphTargetBindErrorKind::DuplicateTarget { first } => write!(
+                f,
+                "'{}' binds the same morph target as bind {}",
+                self.pointer, first
+            ),
+        }
+    }
+}
+
+impl std::error::Error for MorphTargetBindError {}
+
+/// Validates the morph target binds of `expressions` of `VRMC_vrm` 1.0 against `doc`:
+///
+/// - the node of each bind has a mesh
+/// - `index` is below the number of morph targets of the mesh
+/// - `weight` is in `0..=1`
+/// - each expression binds a morph target once at most
+///
+/// Nodes which don't exist in `doc` are skipped, see
+/// [`validate_vrm1_indices`](super::validate_vrm1_indices). Returns an empty `Vec` if all the
+/// binds are valid.
+pub fn validate_morph_target_binds(
+    expressions: &Expressions,
+    doc: &gltf::Document,
+) -> Vec<MorphTargetBindError> {
+    let json = doc.as_json();
+    let mut errors = Vec::new();
+
+    let pointer = format!("/extensions/{}", VRMC_VRM);
+    for (name, pointer, expression) in sorted_expressions(expressions, &pointer) {
+        let mut checker = Checker {
+            expression: name,
+            targets: Vec::new(),
+            errors: &mut errors,
+        };
+        for (i, bind) in expression.morph_target_binds.iter().flatten().enumerate() {
+            let pointer = format!("{}/morphTargetBinds/{}", pointer, i);
+            let node = bind.node.value();
+            let Some(node_json) = json.nodes.get(node) else {
+                continue;
+            };
+            let Some(mesh) = node_json.mesh else {
+                checker.push(&pointer, MorphTargetBindErrorKind::MissingMesh { node });
+                continue;
+            };
+            checker.check(doc, &pointer, i, mesh.value(), bind.index as i64);
+            checker.check_weight(&pointer, bind.weight, 1.0);
+        }
+    }
+    errors
+}
+
+/// Validates the binds of `blend_shape` of VRM 0.x against `doc`:
+///
+/// - `index` is below the number of morph targets of the mesh
+/// - `weight` is in `0..=100`
+/// - each blend shape group binds a morph target once at most
+///
+/// Meshes which don't exist in `doc` are skipped, see
+/// [`validate_vrm0_indices`](super::validate_vrm0_indices). Returns an empty `Vec` if all the
+/// binds are valid.
+pub fn validate_vrm0_morph_target_binds(
+    blend_shape: &VRMBlendShape,
+    doc: &gltf::Document,
+) -> Vec<MorphTargetBindError> {
+    let mut errors = Vec::new();
+
+    let pointer = format!("/extensions/{}/blendShapeMaster/blendShapeGroups", VRM);
+    for (i, group) in blend_shape.blend_shape_groups.iter().flatten().enumerate() {
+        let name = group
+            .name
+            .clone()
+            .or_else(|| group.preset_name.as_ref().map(name_of))
+            .unwrap_or_default();
+        let mut checker = Checker {
+            expression: name,
+            targets: Vec::new(),
+            errors: &mut errors,
+        };
+        for (j, bind) in group.binds.iter().flatten().enumerate() {
+            let pointer = format!("{}/{}/binds/{}", pointer, i, j);
+            let VRMBlendShapeBind {
+                mesh: Some(mesh),
+                index,
+                weight,
+                ..
+            } = bind
+            else {
+                continue;
+            };
+            if let Some(index) = index {
+                checker.check(doc, &pointer, j, mesh.value(), *index);
+            }
+            if let Some(weight) = weight {
+                checker.check_weight(&pointer, *weight, 100.0);
+            }
+        }
+    }
+    errors
+}
+
+/// Validates the binds of an expression
+struct Checker<'a> {
+    expression: String,
+    /// The mesh, the morph target and the bind of each valid bind
+    targets: Vec<(usize, i64, usize)>,
+    errors: &'a mut Vec<MorphTargetBindError>,
+}
+
+impl Checker<'_> {
+    fn push(&mut self, pointer: &str, kind: MorphTargetBindErrorKind) {
+        self.errors.push(MorphTargetBindError {
+            expression: self.expression.clone(),
+            pointer: pointer.to_owned(),
+            kind,
+        });
+    }
+
+    fn check(&mut self, doc: &gltf::Document, pointer: &str, bind: usize, mesh: usize, index: i64) {
+        let Some(mesh_json) = doc.as_json().meshes.get(mesh) else {
+            return;
+        };
+        // NOTE: all primitives of a mesh must have the same number of morph targets
+        let count = mesh_json
+            .primitives
+            .iter()
+            .map(|primitive| primitive.targets.as_ref().map_or(0, Vec::len))
+            .min()
+            .unwrap_or(0);
+        if usize::try_from(index).map_or(true, |index| index >= count) {
+            self.push(
+                pointer,
+                MorphTargetBindErrorKind::TargetOutOfRange { mesh, index, count },
+            );
+            return;
+        }
+
+        match self
+            .targets
+            .iter()
+            .find(|target| (target.0, target.1) == (mesh, index))
+        {
+            Some(&(_, _, first)) => {
+                self.push(pointer, MorphTargetBindErrorKind::DuplicateTarget { first })
+            }
+            None => self.targets.push((mesh, index, bind)),
+        }
+    }
+
+    fn check_weight(&mut self, pointer: &str, weight: f64, max: f64) {
+        if !(0.0..=max).contains(&weight) {
+            self.push(
+                pointer,
+                MorphTargetBindErrorKind::WeightOutOfRange { weight, max },
+            );
+        }
+    }
+}
@@ -0,0 +1,178 @@
+use serde::{ser::SerializeStruct, Serialize};
+
+use super::{
+    validate_humanoid, validate_humanoid_hierarchy, validate_morph_target_binds,
+    validate_spring_bone, validate_vrm0_humanoid_hierarchy, validate_vrm0_indices,
+    validate_vrm0_morph_target_binds, validate_vrm1_indices, Severity,
+};
+use crate::{
+    document::{Vrm1Extensions, VrmDocument, VrmExtensions},
+    error::push_pointer_token,
+    migrate::name_of,
+    vrm_0_0::{VRM0Schema, VRM},
+    vrmc_vrm_1_0::VRMC_VRM,
+};
+
+/// Problems found by all the validators, in the spirit of the output of the
+/// [glTF validator](https://github.com/KhronosGroup/glTF-Validator).
+///
+/// It is serialized with the number of messages of each severity:
+///
+/// ```json
+/// {
+///   "numErrors": 1,
+///   "numWarnings": 0,
+///   "numInfos": 0,
+///   "messages": [
+///     {
+///       "code": "HUMANOID_MISSING_REQUIRED_BONE",
+///       "severity": "error",
+///       "pointer": "/extensions/VRMC_vrm/humanoid/humanBones/hips",
+///       "message": "required bone 'hips' is missing"
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ValidationReport {
+    pub messages: Vec<ValidationMessage>,
+}
+
+/// A problem found by a validator
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ValidationMessage {
+    /// Stable identifier of the kind of the problem, e.g. `SPRING_NOT_CHAINED`
+    pub code: &'static str,
+
+    /// How serious the problem is
+    pub severity: Severity,
+
+    /// JSON pointer to the problematic property in the glTF JSON
+    pub pointer: String,
+
+    /// Human-readable description of the problem
+    pub message: String,
+}
+
+impl ValidationReport {
+    /// Returns the number of messages of `severity`
+    pub fn count(&self, severity: Severity) -> usize {
+        self.messages
+            .iter()
+            .filter(|message| message.severity == severity)
+            .count()
+    }
+
+    /// Returns `true` if there is no error. Warnings and infos are allowed.
+    pub fn is_valid(&self) -> bool {
+        self.count(Severity::Error) == 0
+    }
+
+    fn push(
+        &mut self,
+        code: &'static str,
+        severity: Severity,
+        pointer: impl Into<String>,
+        message: impl ToString,
+    ) {
+        self.messages.push(ValidationMessage {
+            code,
+            severity,
+            pointer: pointer.into(),
+            message: message.to_string(),
+        });
+    }
+}
+
+impl Serialize for ValidationReport {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut state = serializer.serialize_struct("ValidationReport", 4)?;
+        state.serialize_field("numErrors", &self.count(Severity::Error))?;
+        state.serialize_field("numWarnings", &self.count(Severity::Warning))?;
+        state.serialize_field("numInfos", &self.count(Severity::Info))?;
+        state.serialize_field("messages", &self.messages)?;
+        state.end()
+    }
+}
+
+/// Runs all the validators on the VRM extensions of `vrm`
+pub fn validate_document(vrm: &VrmDocument) -> ValidationReport {
+    match &vrm.extensions {
+        VrmExtensions::Vrm0(vrm0) => validate_vrm0(vrm0, &vrm.gltf.document),
+        VrmExtensions::Vrm1(vrm1) => validate_vrm1(vrm1, &vrm.gltf.document),
+    }
+}
+
+/// Runs all the validators on the VRM 1.0 extensions
+pub fn validate_vrm1(vrm1: &Vrm1Extensions, doc: &gltf::Document) -> ValidationReport {
+    let mut report = ValidationReport::default();
+    let bone_pointer = |bone| {
+        let mut pointer = format!("/extensions/{}/humanoid/humanBones", VRMC_VRM);
+        push_pointer_token(&mut pointer, &name_of(&bone));
+        pointer
+    };
+
+    let humanoid = &vrm1.vrmc_vrm.humanoid;
+    for error in validate_humanoid(humanoid) {
+        report.push(
+            error.code(),
+            Severity::Error,
+            bone_pointer(error.bone()),
+            error,
+        );
+    }
+    for error in validate_humanoid_hierarchy(humanoid, doc) {
+        let pointer = format!("{}/node", bone_pointer(error.bone));
+        report.push(error.code(), Severity::Error, pointer, error);
+    }
+    for error in validate_vrm1_indices(vrm1, doc) {
+        let pointer = error.pointer.clone();
+        report.push(error.code(), Severity::Error, pointer, error);
+    }
+    if let Some(expressions) = &vrm1.vrmc_vrm.expressions {
+        for error in validate_morph_target_binds(expressions, doc) {
+            let pointer = error.pointer.clone();
+            report.push(error.code(), Severity::Error, pointer, error);
+        }
+    }
+    if let Some(spring_bone) = &vrm1.vrmc_spring_bone {
+        for issue in validate_spring_bone(spring_bone, doc) {
+            let pointer = issue.pointer.clone();
+            report.push(issue.code(), issue.severity(), pointer, issue);
+        }
+    }
+    report
+}
+
+/// Runs all the validators on the `VRM` extension of VRM 0.x
+pub fn validate_vrm0(vrm0: &VRM0Schema, doc: &gltf::Document) -> ValidationReport {
+    let mut report = ValidationReport::default();
+
+    if let Some(humanoid) = &vrm0.humanoid {
+        let human_bones = humanoid.human_bones.as_deref().unwrap_or_default();
+        for error in validate_vrm0_humanoid_hierarchy(humanoid, doc) {
+            // NOTE: the first bone wins if a bone is duplicated
+            let bone = Some(name_of(&error.bone));
+            let index = human_bones
+                .iter()
+                .position(|human_bone| human_bone.bone.as_ref().map(name_of) == bone)
+                .unwrap_or_default();
+            let pointer = format!("/extensions/{}/humanoid/humanBones/{}/node", VRM, index);
+            report.push(error.code(), Severity::Error, pointer, error);
+        }
+    }
+    for error in validate_vrm0_indices(vrm0, doc) {
+        let pointer = error.pointer.clone();
+        report.push(error.code(), Severity::Error, pointer, error);
+    }
+    if let Some(blend_shape) = &vrm0.blend_shape_master {
+        for error in validate_vrm0_morph_target_binds(blend_shape, doc) {
+            let pointer = error.pointer.clone();
+            report.push(error.code(), Severity::Error, pointer, error);
+        }
+    }
+    report
+}
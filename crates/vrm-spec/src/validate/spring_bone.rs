@@ -0,0 +1,248 @@
+use std::fmt;
+
+#[cfg(feature = "rustc_hash")]
+use rustc_hash::FxHashMap as HashMap;
+#[cfg(not(feature = "rustc_hash"))]
+use std::collections::HashMap;
+
+use super::{node_parents, Severity};
+use crate::vrmc_spring_bone_1_0::{
+    ColliderShapeError, SpringBoneJoint, VrmcSpringBoneSchema, VRMC_SPRING_BONE,
+};
+
+/// A problem of a spring of `VRMC_springBone`
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpringBoneIssue {
+    /// JSON pointer to the problematic property in the glTF JSON, e.g.
+    /// `/extensions/VRMC_springBone/springs/0/joints/1/node`
+    pub pointer: String,
+
+    pub kind: SpringBoneIssueKind,
+}
+
+/// The reason of a [`SpringBoneIssue`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum SpringBoneIssueKind {
+    /// The node of a joint is not a child of the node of the previous joint
+    NotChained { node: usize, previous: usize },
+
+    /// The node of a joint is a joint of another spring as well
+    SharedJoint { node: usize, spring: usize },
+
+    /// A collider group which doesn't exist is referenced
+    MissingColliderGroup { group: i64 },
+
+    /// A collider group is referenced twice by a spring
+    DuplicateColliderGroup { group: i64 },
+
+    /// A spring has less than 2 joints, so there is no bone to sway
+    TooFewJoints { count: usize },
+
+    /// A parameter of a joint is out of the range of the specification
+    ParameterOutOfRange {
+        parameter: &'static str,
+        value: f64,
+        min: f64,
+        max: Option<f64>,
+    },
+
+    /// `gravityDir` of a joint is not a unit vector
+    NotNormalized { gravity_dir: [f64; 3] },
+
+    /// The shape of a collider has none or both of `sphere` and `capsule`
+    InvalidColliderShape { reason: &'static str },
+}
+
+impl SpringBoneIssue {
+    /// How serious the problem is. A broken structure is an error while parameters which
+    /// implementations clamp or normalize are warnings.
+    pub fn severity(&self) -> Severity {
+        match self.kind {
+            SpringBoneIssueKind::NotChained { .. }
+            | SpringBoneIssueKind::SharedJoint { .. }
+            | SpringBoneIssueKind::MissingColliderGroup { .. }
+            | SpringBoneIssueKind::InvalidColliderShape { .. } => Severity::Error,
+            SpringBoneIssueKind::DuplicateColliderGroup { .. }
+            | SpringBoneIssueKind::TooFewJoints { .. }
+            | SpringBoneIssueKind::ParameterOutOfRange { .. }
+            | SpringBoneIssueKind::NotNormalized { .. } => Severity::Warning,
+        }
+    }
+
+    /// Stable identifier of the kind of the problem, e.g. `SPRING_NOT_CHAINED`
+    pub fn code(&self) -> &'static str {
+        match self.kind {
+            SpringBoneIssueKind::NotChained { .. } => "SPRING_NOT_CHAINED",
+            SpringBoneIssueKind::SharedJoint { .. } => "SPRING_SHARED_JOINT",
+            SpringBoneIssueKind::MissingColliderGroup { .. } => "SPRING_MISSING_COLLIDER_GROUP",
+            SpringBoneIssueKind::DuplicateColliderGroup { .. } => "SPRING_DUPLICATE_COLLIDER_GROUP",
+            SpringBoneIssueKind::TooFewJoints { .. } => "SPRING_TOO_FEW_JOINTS",
+            SpringBoneIssueKind::ParameterOutOfRange { .. } => "SPRING_PARAMETER_OUT_OF_RANGE",
+            SpringBoneIssueKind::NotNormalized { .. } => "SPRING_GRAVITY_DIR_NOT_NORMALIZED",
+            SpringBoneIssueKind::InvalidColliderShape { .. } => "SPRING_INVALID_COLLIDER_SHAPE",
+        }
+    }
+}
+
+impl fmt::Display for SpringBoneIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            SpringBoneIssueKind::NotChained { node, previous } => write!(
+                f,
+                "node {} is not a child of node {} of the previous joint",
+                node, previous
+            ),
+            SpringBoneIssueKind::SharedJoint { node, spring } => {
+                write!(f, "node {} is a joint of spring {} as well", node, spring)
+            }
+            SpringBoneIssueKind::MissingColliderGroup { group } => {
+                write!(f, "collider group {} does not exist", group)
+            }
+            SpringBoneIssueKind::DuplicateColliderGroup { group } => {
+                write!(f, "collider group {} is referenced twice", group)
+            }
+            SpringBoneIssueKind::TooFewJoints { count } => {
+                write!(f, "the spring has {} joints; 2 are needed to sway", count)
+            }
+            SpringBoneIssueKind::ParameterOutOfRange {
+                parameter,
+                value,
+                min,
+                max,
+            } => match max {
+                Some(max) => write!(
+                    f,
+                    "{} {} is out of range {}..={}",
+                    parameter, value, min, max
+                ),
+                None => write!(f, "{} {} is less than {}", parameter, value, min),
+            },
+            SpringBoneIssueKind::NotNormalized { gravity_dir } => {
+                write!(f, "gravityDir {:?} is not a unit vector", gravity_dir)
+            }
+            SpringBoneIssueKind::InvalidColliderShape { reason } => {
+                write!(f, "invalid collider shape: {}", reason)
+            }
+        }
+    }
+}
+
+/// Validates the springs of `spring_bone` against the rules of `VRMC_springBone` 1.0 and the node
+/// tree of `doc`:
+///
+/// - the node of each joint is a child of the node of the previous joint
+/// - a node is a joint of one spring at most
+/// - `colliderGroups` of each spring refer to existing collider groups once
+/// - the parameters of each joint are in the ranges of the specification
+/// - the shape of each collider is either a sphere or a capsule
+///
+/// Nodes which don't exist in `doc` are skipped, see
+/// [`validate_vrm1_indices`](super::validate_vrm1_indices). Returns an empty `Vec` if the springs
+/// are valid.
+pub fn validate_spring_bone(
+    spring_bone: &VrmcSpringBoneSchema,
+    doc: &gltf::Document,
+) -> Vec<SpringBoneIssue> {
+    let parents = node_parents(doc);
+    let collider_groups = spring_bone.collider_groups.as_deref().unwrap_or_default();
+    let mut issues = Vec::new();
+    let mut push = |pointer: String, kind| issues.push(SpringBoneIssue { pointer, kind });
+
+    for (i, collider) in spring_bone.colliders.iter().flatten().enumerate() {
+        if let Err(ColliderShapeError { reason }) = collider.shape.kind() {
+            push(
+                format!("/extensions/{}/colliders/{}/shape", VRMC_SPRING_BONE, i),
+                SpringBoneIssueKind::InvalidColliderShape { reason },
+            );
+        }
+    }
+
+    // the spring of each joint node
+    let mut springs: HashMap<usize, usize> = HashMap::default();
+    for (i, spring) in spring_bone.springs.iter().flatten().enumerate() {
+        let pointer = format!("/extensions/{}/springs/{}", VRMC_SPRING_BONE, i);
+        if spring.joints.len() < 2 {
+            push(
+                format!("{}/joints", pointer),
+                SpringBoneIssueKind::TooFewJoints {
+                    count: spring.joints.len(),
+                },
+            );
+        }
+
+        let mut previous: Option<usize> = None;
+        for (j, joint) in spring.joints.iter().enumerate() {
+            let pointer = format!("{}/joints/{}", pointer, j);
+            let node = joint.node.value();
+            if let Some(previous) = previous {
+                let exists = node < parents.len() && previous < parents.len();
+                if exists && parents[node] != Some(previous) {
+                    push(
+                        format!("{}/node", pointer),
+                        SpringBoneIssueKind::NotChained { node, previous },
+                    );
+                }
+            }
+            previous = Some(node);
+
+            let spring = *springs.entry(node).or_insert(i);
+            if spring != i {
+                push(
+                    format!("{}/node", pointer),
+                    SpringBoneIssueKind::SharedJoint { node, spring },
+                );
+            }
+
+            for (parameter, value, min, max) in parameters(joint) {
+                let Some(value) = value else {
+                    continue;
+                };
+                if value < min || max.map_or(false, |max| value > max) {
+                    push(
+                        format!("{}/{}", pointer, parameter),
+                        SpringBoneIssueKind::ParameterOutOfRange {
+                            parameter,
+                            value,
+                            min,
+                            max,
+                        },
+                    );
+                }
+            }
+            if let Some(gravity_dir) = joint.gravity_dir {
+                let length = gravity_dir.iter().map(|v| v * v).sum::<f64>().sqrt();
+                if (length - 1.0).abs() > 1e-3 {
+                    push(
+                        format!("{}/gravityDir", pointer),
+                        SpringBoneIssueKind::NotNormalized { gravity_dir },
+                    );
+                }
+            }
+        }
+
+        let groups = spring.collider_groups.as_deref().unwrap_or_default();
+        for (j, &group) in groups.iter().enumerate() {
+            let pointer = format!("{}/colliderGroups/{}", pointer, j);
+            if usize::try_from(group).map_or(true, |group| group >= collider_groups.len()) {
+                push(pointer, SpringBoneIssueKind::MissingColliderGroup { group });
+            } else if groups[..j].contains(&group) {
+                push(
+                    pointer,
+                    SpringBoneIssueKind::DuplicateColliderGroup { group },
+                );
+            }
+        }
+    }
+    issues
+}
+
+/// Returns the name, the value, the minimum and the maximum of the parameters of `joint` which
+/// have a range in the specification
+fn parameters(joint: &SpringBoneJoint) -> [(&'static str, Option<f64>, f64, Option<f64>); 4] {
+    [
+        ("dragForce", joint.drag_force, 0.0, Some(1.0)),
+        ("gravityPower", joint.gravity_power, 0.0, None),
+        ("hitRadius", joint.hit_radius, 0.0, None),
+        ("stiffness", joint.stiffness, 0.0, None),
+    ]
+}
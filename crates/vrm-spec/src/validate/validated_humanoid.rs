@@ -0,0 +1,279 @@
+#[cfg(feature = "rustc_hash")]
+use rustc_hash::FxHashMap as HashMap;
+#[cfg(not(feature = "rustc_hash"))]
+use std::collections::HashMap;
+
+use super::{validate_humanoid, HumanoidError};
+use crate::vrmc_vrm_1_0::{HumanBoneName, Humanoid};
+
+/// A humanoid of `VRMC_vrm` 1.0 which passed [`validate_humanoid`], so that the required bones
+/// are always available.
+///
+/// The required bones are returned as plain node indices, e.g. [`hips`](Self::hips), and the
+/// optional bones as `Option`, e.g. [`chest`](Self::chest). Any bone is also available by
+/// [`get`](Self::get). The hierarchy of the nodes is not validated, see
+/// [`validate_humanoid_hierarchy`](super::validate_humanoid_hierarchy).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidatedHumanoid {
+    nodes: HashMap<HumanBoneName, usize>,
+}
+
+impl TryFrom<&Humanoid> for ValidatedHumanoid {
+    type Error = Vec<HumanoidError>;
+
+    fn try_from(humanoid: &Humanoid) -> Result<Self, Self::Error> {
+        let errors = validate_humanoid(humanoid);
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+        let nodes = humanoid
+            .human_bones
+            .0
+            .iter()
+            .filter_map(|(&bone, human_bone)| {
+                let node = human_bone.as_ref()?.node?;
+                Some((bone, node.value()))
+            })
+            .collect();
+        Ok(ValidatedHumanoid { nodes })
+    }
+}
+
+impl ValidatedHumanoid {
+    /// Returns the node of `bone` if the humanoid has it
+    pub fn get(&self, bone: HumanBoneName) -> Option<usize> {
+        self.nodes.get(&bone).copied()
+    }
+
+    /// Iterates the bones and their nodes in the order of the hierarchy, where a parent comes
+    /// before its children
+    pub fn iter(&self) -> impl Iterator<Item = (HumanBoneName, usize)> + '_ {
+        HumanBoneName::ALL
+            .iter()
+            .filter_map(move |&bone| self.get(bone).map(|node| (bone, node)))
+    }
+
+    fn required(&self, bone: HumanBoneName) -> usize {
+        self.nodes[&bone]
+    }
+
+    pub fn hips(&self) -> usize {
+        self.required(HumanBoneName::Hips)
+    }
+
+    pub fn spine(&self) -> usize {
+        self.required(HumanBoneName::Spine)
+    }
+
+    pub fn head(&self) -> usize {
+        self.required(HumanBoneName::Head)
+    }
+
+    pub fn left_upper_leg(&self) -> usize {
+        self.required(HumanBoneName::LeftUpperLeg)
+    }
+
+    pub fn left_lower_leg(&self) -> usize {
+        self.required(HumanBoneName::LeftLowerLeg)
+    }
+
+    pub fn left_foot(&self) -> usize {
+        self.required(HumanBoneName::LeftFoot)
+    }
+
+    pub fn right_upper_leg(&self) -> usize {
+        self.required(HumanBoneName::RightUpperLeg)
+    }
+
+    pub fn right_lower_leg(&self) -> usize {
+        self.required(HumanBoneName::RightLowerLeg)
+    }
+
+    pub fn right_foot(&self) -> usize {
+        self.required(HumanBoneName::RightFoot)
+    }
+
+    pub fn left_upper_arm(&self) -> usize {
+        self.required(HumanBoneName::LeftUpperArm)
+    }
+
+    pub fn left_lower_arm(&self) -> usize {
+        self.required(HumanBoneName::LeftLowerArm)
+    }
+
+    pub fn left_hand(&self) -> usize {
+        self.required(HumanBoneName::LeftHand)
+    }
+
+    pub fn right_upper_arm(&self) -> usize {
+        self.required(HumanBoneName::RightUpperArm)
+    }
+
+    pub fn right_lower_arm(&self) -> usize {
+        self.required(HumanBoneName::RightLowerArm)
+    }
+
+    pub fn right_hand(&self) -> usize {
+        self.required(HumanBoneName::RightHand)
+    }
+
+    pub fn chest(&self) -> Option<usize> {
+        self.get(HumanBoneName::Chest)
+    }
+
+    pub fn upper_chest(&self) -> Option<usize> {
+        self.get(HumanBoneName::UpperChest)
+    }
+
+    pub fn neck(&self) -> Option<usize> {
+        self.get(HumanBoneName::Neck)
+    }
+
+    pub fn left_eye(&self) -> Option<usize> {
+        self.get(HumanBoneName::LeftEye)
+    }
+
+    pub fn right_eye(&self) -> Option<usize> {
+        self.get(HumanBoneName::RightEye)
+    }
+
+    pub fn jaw(&self) -> Option<usize> {
+        self.get(HumanBoneName::Jaw)
+    }
+
+    pub fn left_toes(&self) -> Option<usize> {
+        self.get(HumanBoneName::LeftToes)
+    }
+
+    pub fn right_toes(&self) -> Option<usize> {
+        self.get(HumanBoneName::RightToes)
+    }
+
+    pub fn left_shoulder(&self) -> Option<usize> {
+        self.get(HumanBoneName::LeftShoulder)
+    }
+
+    pub fn right_shoulder(&self) -> Option<usize> {
+        self.get(HumanBoneName::RightShoulder)
+    }
+
+    pub fn left_thumb_metacarpal(&self) -> Option<usize> {
+        self.get(HumanBoneName::LeftThumbMetacarpal)
+    }
+
+    pub fn left_thumb_proximal(&self) -> Option<usize> {
+        self.get(HumanBoneName::LeftThumbProximal)
+    }
+
+    pub fn left_thumb_distal(&self) -> Option<usize> {
+        self.get(HumanBoneName::LeftThumbDistal)
+    }
+
+    pub fn left_index_proximal(&self) -> Option<usize> {
+        self.get(HumanBoneName::LeftIndexProximal)
+    }
+
+    pub fn left_index_intermediate(&self) -> Option<usize> {
+        self.get(HumanBoneName::LeftIndexIntermediate)
+    }
+
+    pub fn left_index_distal(&self) -> Option<usize> {
+        self.get(HumanBoneName::LeftIndexDistal)
+    }
+
+    pub fn left_middle_proximal(&self) -> Option<usize> {
+        self.get(HumanBoneName::LeftMiddleProximal)
+    }
+
+    pub fn left_middle_intermediate(&self) -> Option<usize> {
+        self.get(HumanBoneName::LeftMiddleIntermediate)
+    }
+
+    pub fn left_middle_distal(&self) -> Option<usize> {
+        self.get(HumanBoneName::LeftMiddleDistal)
+    }
+
+    pub fn left_ring_proximal(&self) -> Option<usize> {
+        self.get(HumanBoneName::LeftRingProximal)
+    }
+
+    pub fn left_ring_intermediate(&self) -> Option<usize> {
+        self.get(HumanBoneName::LeftRingIntermediate)
+    }
+
+    pub fn left_ring_distal(&self) -> Option<usize> {
+        self.get(HumanBoneName::LeftRingDistal)
+    }
+
+    pub fn left_little_proximal(&self) -> Option<usize> {
+        self.get(HumanBoneName::LeftLittleProximal)
+    }
+
+    pub fn left_little_intermediate(&self) -> Option<usize> {
+        self.get(HumanBoneName::LeftLittleIntermediate)
+    }
+
+    pub fn left_little_distal(&self) -> Option<usize> {
+        self.get(HumanBoneName::LeftLittleDistal)
+    }
+
+    pub fn right_thumb_metacarpal(&self) -> Option<usize> {
+        self.get(HumanBoneName::RightThumbMetacarpal)
+    }
+
+    pub fn right_thumb_proximal(&self) -> Option<usize> {
+        self.get(HumanBoneName::RightThumbProximal)
+    }
+
+    pub fn right_thumb_distal(&self) -> Option<usize> {
+        self.get(HumanBoneName::RightThumbDistal)
+    }
+
+    pub fn right_index_proximal(&self) -> Option<usize> {
+        self.get(HumanBoneName::RightIndexProximal)
+    }
+
+    pub fn right_index_intermediate(&self) -> Option<usize> {
+        self.get(HumanBoneName::RightIndexIntermediate)
+    }
+
+    pub fn right_index_distal(&self) -> Option<usize> {
+        self.get(HumanBoneName::RightIndexDistal)
+    }
+
+    pub fn right_middle_proximal(&self) -> Option<usize> {
+        self.get(HumanBoneName::RightMiddleProximal)
+    }
+
+    pub fn right_middle_intermediate(&self) -> Option<usize> {
+        self.get(HumanBoneName::RightMiddleIntermediate)
+    }
+
+    pub fn right_middle_distal(&self) -> Option<usize> {
+        self.get(HumanBoneName::RightMiddleDistal)
+    }
+
+    pub fn right_ring_proximal(&self) -> Option<usize> {
+        self.get(HumanBoneName::RightRingProximal)
+    }
+
+    pub fn right_ring_intermediate(&self) -> Option<usize> {
+        self.get(HumanBoneName::RightRingIntermediate)
+    }
+
+    pub fn right_ring_distal(&self) -> Option<usize> {
+        self.get(HumanBoneName::RightRingDistal)
+    }
+
+    pub fn right_little_proximal(&self) -> Option<usize> {
+        self.get(HumanBoneName::RightLittleProximal)
+    }
+
+    pub fn right_little_intermediate(&self) -> Option<usize> {
+        self.get(HumanBoneName::RightLittleIntermediate)
+    }
+
+    pub fn right_little_distal(&self) -> Option<usize> {
+        self.get(HumanBoneName::RightLittleDistal)
+    }
+}
@@ -13,9 +13,10 @@ use crate::serde_utils::{
     deserialize_option_index, deserialize_option_map_and_skip_nullable,
     deserialize_option_map_index,
 };
+use crate::vrmc_vrm_1_0::{HumanBoneGroup, HumanBoneSide};
 
 /// VRM extension is for 3d humanoid avatars (and models) in VR applications.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct VRM0Schema {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -43,17 +44,27 @@ pub struct VRM0Schema {
     /// Version of VRM specification. 0.0
     #[serde(skip_serializing_if = "Option::is_none")]
     pub spec_version: Option<String>,
+
+    /// Properties which are not defined in the schema
+    #[cfg(feature = "preserve_unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, serde_json::Value>,
 }
 
 /// BlendShapeAvatar of UniVRM
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct VRMBlendShape {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub blend_shape_groups: Option<Vec<VRMBlendShapeGroup>>,
+
+    /// Properties which are not defined in the schema
+    #[cfg(feature = "preserve_unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct VRMBlendShapeGroup {
     /// Low level blendshape references.
@@ -75,9 +86,14 @@ pub struct VRMBlendShapeGroup {
     /// Predefined Expression name
     #[serde(skip_serializing_if = "Option::is_none")]
     pub preset_name: Option<PresetName>,
+
+    /// Properties which are not defined in the schema
+    #[cfg(feature = "preserve_unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct VRMBlendShapeBind {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub index: Option<i64>,
@@ -95,9 +111,14 @@ pub struct VRMBlendShapeBind {
     /// SkinnedMeshRenderer.SetBlendShapeWeight
     #[serde(skip_serializing_if = "Option::is_none")]
     pub weight: Option<f64>,
+
+    /// Properties which are not defined in the schema
+    #[cfg(feature = "preserve_unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct VRMBlendShapeMaterialBind {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -108,9 +129,14 @@ pub struct VRMBlendShapeMaterialBind {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub target_value: Option<Vec<f64>>,
+
+    /// Properties which are not defined in the schema
+    #[cfg(feature = "preserve_unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct VRMFirstPerson {
     /// The bone whose rendering should be turned off in first-person view. Usually Head is
@@ -149,9 +175,14 @@ pub struct VRMFirstPerson {
     /// Switch display / undisplay for each mesh in first-person view or the others.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub mesh_annotations: Option<Vec<VRMFirstPersonMeshAnnotation>>,
+
+    /// Properties which are not defined in the schema
+    #[cfg(feature = "preserve_unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 /// Vector3 but has optional x,y,z.
 ///
 /// normally x,y,z should not be optional but the VRM 0.0 spec allows it
@@ -164,6 +195,11 @@ pub struct OptionalVector3 {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub z: Option<f64>,
+
+    /// Properties which are not defined in the schema
+    #[cfg(feature = "preserve_unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The target position of the VR headset in first-person view. It is assumed that an offset
@@ -171,7 +207,7 @@ pub struct OptionalVector3 {
 pub type FirstPersonBoneOffset = OptionalVector3;
 
 /// Eye controller setting.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct VRMFirstPersonDegreeMap {
     /// None linear mapping params. time, value, inTangent, outTangent
@@ -185,9 +221,14 @@ pub struct VRMFirstPersonDegreeMap {
     /// Look at map range degree from xRange.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub y_range: Option<f64>,
+
+    /// Properties which are not defined in the schema
+    #[cfg(feature = "preserve_unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct VRMFirstPersonMeshAnnotation {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -202,9 +243,14 @@ pub struct VRMFirstPersonMeshAnnotation {
     pub mesh: Option<gltf::json::Index<gltf::json::Mesh>>,
     #[cfg(not(feature = "gltf_index"))]
     pub mesh: Option<i64>,
+
+    /// Properties which are not defined in the schema
+    #[cfg(feature = "preserve_unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct VRMHumanoid {
     /// Unity's HumanDescription.armStretch
@@ -241,9 +287,14 @@ pub struct VRMHumanoid {
     /// Unity's HumanDescription.upperLegTwist
     #[serde(skip_serializing_if = "Option::is_none")]
     pub upper_leg_twist: Option<f64>,
+
+    /// Properties which are not defined in the schema
+    #[cfg(feature = "preserve_unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct VRMHumanoidBone {
     /// Unity's HumanLimit.axisLength
@@ -280,6 +331,11 @@ pub struct VRMHumanoidBone {
     /// Unity's HumanLimit.useDefaultValues
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_default_values: Option<bool>,
+
+    /// Properties which are not defined in the schema
+    #[cfg(feature = "preserve_unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Unity's HumanLimit.center
@@ -291,7 +347,7 @@ pub type Max = OptionalVector3;
 /// Unity's HumanLimit.min
 pub type Min = OptionalVector3;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct VRMMaterial {
     #[serde(
@@ -331,9 +387,14 @@ pub struct VRMMaterial {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub vector_properties: Option<HashMap<String, Vec<f64>>>,
+
+    /// Properties which are not defined in the schema
+    #[cfg(feature = "preserve_unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct VRMMeta {
     /// A person who can perform with this avatar
@@ -395,10 +456,15 @@ pub struct VRMMeta {
     /// Permission to perform violent acts with this avatar
     #[serde(skip_serializing_if = "Option::is_none")]
     pub violent_ussage_name: Option<UssageName>,
+
+    /// Properties which are not defined in the schema
+    #[cfg(feature = "preserve_unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The setting of automatic animation of string-like objects such as tails and hairs.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct VRMSecondaryAnimation {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -406,9 +472,14 @@ pub struct VRMSecondaryAnimation {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub collider_groups: Option<Vec<VRMSecondaryAnimationColliderGroup>>,
+
+    /// Properties which are not defined in the schema
+    #[cfg(feature = "preserve_unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct VRMSecondaryAnimationSpring {
     /// Specify the node index of the root bone of the swaying object.
@@ -459,13 +530,18 @@ pub struct VRMSecondaryAnimationSpring {
     /// The resilience of the swaying object (the power of returning to the initial pose).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub stiffiness: Option<f64>,
+
+    /// Properties which are not defined in the schema
+    #[cfg(feature = "preserve_unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The direction of gravity. Set (0, -1, 0) for simulating the gravity. Set (1, 0, 0) for
 /// simulating the wind.
 pub type GravityDir = OptionalVector3;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct VRMSecondaryAnimationColliderGroup {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub colliders: Option<Vec<Collider>>,
@@ -479,9 +555,14 @@ pub struct VRMSecondaryAnimationColliderGroup {
     pub node: Option<gltf::json::Index<gltf::json::Node>>,
     #[cfg(not(feature = "gltf_index"))]
     pub node: Option<i64>,
+
+    /// Properties which are not defined in the schema
+    #[cfg(feature = "preserve_unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Collider {
     /// The local coordinate from the node of the collider group in *left-handed* Y-up coordinate.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -490,13 +571,18 @@ pub struct Collider {
     /// The radius of the collider.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub radius: Option<f64>,
+
+    /// Properties which are not defined in the schema
+    #[cfg(feature = "preserve_unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The local coordinate from the node of the collider group in *left-handed* Y-up coordinate.
 pub type Offset = OptionalVector3;
 
 /// Predefined Expression name.
-#[derive(Debug, Clone, Copy, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum PresetName {
     A,
@@ -527,7 +613,7 @@ pub enum LookAtTypeName {
 }
 
 /// Human bone name.
-#[derive(Debug, Clone, Copy, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum Bone {
     Chest,
@@ -587,6 +673,324 @@ pub enum Bone {
     UpperChest,
 }
 
+impl Bone {
+    /// All the bones in the order of the hierarchy, where a parent comes before its children
+    pub const ALL: [Bone; 55] = [
+        Bone::Hips,
+        Bone::Spine,
+        Bone::Chest,
+        Bone::UpperChest,
+        Bone::Neck,
+        Bone::Head,
+        Bone::LeftEye,
+        Bone::RightEye,
+        Bone::Jaw,
+        Bone::LeftUpperLeg,
+        Bone::LeftLowerLeg,
+        Bone::LeftFoot,
+        Bone::LeftToes,
+        Bone::RightUpperLeg,
+        Bone::RightLowerLeg,
+        Bone::RightFoot,
+        Bone::RightToes,
+        Bone::LeftShoulder,
+        Bone::LeftUpperArm,
+        Bone::LeftLowerArm,
+        Bone::LeftHand,
+        Bone::RightShoulder,
+        Bone::RightUpperArm,
+        Bone::RightLowerArm,
+        Bone::RightHand,
+        Bone::LeftThumbProximal,
+        Bone::LeftThumbIntermediate,
+        Bone::LeftThumbDistal,
+        Bone::LeftIndexProximal,
+        Bone::LeftIndexIntermediate,
+        Bone::LeftIndexDistal,
+        Bone::LeftMiddleProximal,
+        Bone::LeftMiddleIntermediate,
+        Bone::LeftMiddleDistal,
+        Bone::LeftRingProximal,
+        Bone::LeftRingIntermediate,
+        Bone::LeftRingDistal,
+        Bone::LeftLittleProximal,
+        Bone::LeftLittleIntermediate,
+        Bone::LeftLittleDistal,
+        Bone::RightThumbProximal,
+        Bone::RightThumbIntermediate,
+        Bone::RightThumbDistal,
+        Bone::RightIndexProximal,
+        Bone::RightIndexIntermediate,
+        Bone::RightIndexDistal,
+        Bone::RightMiddleProximal,
+        Bone::RightMiddleIntermediate,
+        Bone::RightMiddleDistal,
+        Bone::RightRingProximal,
+        Bone::RightRingIntermediate,
+        Bone::RightRingDistal,
+        Bone::RightLittleProximal,
+        Bone::RightLittleIntermediate,
+        Bone::RightLittleDistal,
+    ];
+
+    /// Returns the parent bone in the hierarchy of the specification, e.g. `leftUpperArm` for
+    /// `leftLowerArm`. If the parent is optional and missing in a humanoid, the nearest ancestor
+    /// which exists takes its place.
+    pub const fn parent(self) -> Option<Bone> {
+        match self {
+            Bone::Hips => None,
+            Bone::Spine | Bone::LeftUpperLeg | Bone::RightUpperLeg => Some(Bone::Hips),
+            Bone::Chest => Some(Bone::Spine),
+            Bone::UpperChest => Some(Bone::Chest),
+            Bone::Neck | Bone::LeftShoulder | Bone::RightShoulder => Some(Bone::UpperChest),
+            Bone::Head => Some(Bone::Neck),
+            Bone::LeftEye | Bone::RightEye | Bone::Jaw => Some(Bone::Head),
+            Bone::LeftLowerLeg => Some(Bone::LeftUpperLeg),
+            Bone::LeftFoot => Some(Bone::LeftLowerLeg),
+            Bone::LeftToes => Some(Bone::LeftFoot),
+            Bone::RightLowerLeg => Some(Bone::RightUpperLeg),
+            Bone::RightFoot => Some(Bone::RightLowerLeg),
+            Bone::RightToes => Some(Bone::RightFoot),
+            Bone::LeftUpperArm => Some(Bone::LeftShoulder),
+            Bone::LeftLowerArm => Some(Bone::LeftUpperArm),
+            Bone::LeftHand => Some(Bone::LeftLowerArm),
+            Bone::RightUpperArm => Some(Bone::RightShoulder),
+            Bone::RightLowerArm => Some(Bone::RightUpperArm),
+            Bone::RightHand => Some(Bone::RightLowerArm),
+            Bone::LeftThumbProximal
+            | Bone::LeftIndexProximal
+            | Bone::LeftMiddleProximal
+            | Bone::LeftRingProximal
+            | Bone::LeftLittleProximal => Some(Bone::LeftHand),
+            Bone::LeftThumbIntermediate => Some(Bone::LeftThumbProximal),
+            Bone::LeftThumbDistal => Some(Bone::LeftThumbIntermediate),
+            Bone::LeftIndexIntermediate => Some(Bone::LeftIndexProximal),
+            Bone::LeftIndexDistal => Some(Bone::LeftIndexIntermediate),
+            Bone::LeftMiddleIntermediate => Some(Bone::LeftMiddleProximal),
+            Bone::LeftMiddleDistal => Some(Bone::LeftMiddleIntermediate),
+            Bone::LeftRingIntermediate => Some(Bone::LeftRingProximal),
+            Bone::LeftRingDistal => Some(Bone::LeftRingIntermediate),
+            Bone::LeftLittleIntermediate => Some(Bone::LeftLittleProximal),
+            Bone::LeftLittleDistal => Some(Bone::LeftLittleIntermediate),
+            Bone::RightThumbProximal
+            | Bone::RightIndexProximal
+            | Bone::RightMiddleProximal
+            | Bone::RightRingProximal
+            | Bone::RightLittleProximal => Some(Bone::RightHand),
+            Bone::RightThumbIntermediate => Some(Bone::RightThumbProximal),
+            Bone::RightThumbDistal => Some(Bone::RightThumbIntermediate),
+            Bone::RightIndexIntermediate => Some(Bone::RightIndexProximal),
+            Bone::RightIndexDistal => Some(Bone::RightIndexIntermediate),
+            Bone::RightMiddleIntermediate => Some(Bone::RightMiddleProximal),
+            Bone::RightMiddleDistal => Some(Bone::RightMiddleIntermediate),
+            Bone::RightRingIntermediate => Some(Bone::RightRingProximal),
+            Bone::RightRingDistal => Some(Bone::RightRingIntermediate),
+            Bone::RightLittleIntermediate => Some(Bone::RightLittleProximal),
+            Bone::RightLittleDistal => Some(Bone::RightLittleIntermediate),
+        }
+    }
+
+    /// Returns `true` if a humanoid must have the bone. Unlike VRM 1.0, `chest` and `neck` are
+    /// required in VRM 0.x.
+    pub const fn is_required(self) -> bool {
+        matches!(
+            self,
+            Bone::Hips
+                | Bone::Spine
+                | Bone::Chest
+                | Bone::Neck
+                | Bone::Head
+                | Bone::LeftUpperLeg
+                | Bone::LeftLowerLeg
+                | Bone::LeftFoot
+                | Bone::RightUpperLeg
+                | Bone::RightLowerLeg
+                | Bone::RightFoot
+                | Bone::LeftUpperArm
+                | Bone::LeftLowerArm
+                | Bone::LeftHand
+                | Bone::RightUpperArm
+                | Bone::RightLowerArm
+                | Bone::RightHand
+        )
+    }
+
+    /// Returns the part of the body which the bone belongs to
+    pub const fn group(self) -> HumanBoneGroup {
+        match self {
+            Bone::Hips | Bone::Spine | Bone::Chest | Bone::UpperChest | Bone::Neck => {
+                HumanBoneGroup::Torso
+            }
+            Bone::Head | Bone::LeftEye | Bone::RightEye | Bone::Jaw => HumanBoneGroup::Head,
+            Bone::LeftUpperLeg | Bone::LeftLowerLeg | Bone::LeftFoot | Bone::LeftToes => {
+                HumanBoneGroup::LeftLeg
+            }
+            Bone::RightUpperLeg | Bone::RightLowerLeg | Bone::RightFoot | Bone::RightToes => {
+                HumanBoneGroup::RightLeg
+            }
+            Bone::LeftShoulder | Bone::LeftUpperArm | Bone::LeftLowerArm | Bone::LeftHand => {
+                HumanBoneGroup::LeftArm
+            }
+            Bone::RightShoulder | Bone::RightUpperArm | Bone::RightLowerArm | Bone::RightHand => {
+                HumanBoneGroup::RightArm
+            }
+            Bone::LeftThumbProximal
+            | Bone::LeftThumbIntermediate
+            | Bone::LeftThumbDistal
+            | Bone::LeftIndexProximal
+            | Bone::LeftIndexIntermediate
+            | Bone::LeftIndexDistal
+            | Bone::LeftMiddleProximal
+            | Bone::LeftMiddleIntermediate
+            | Bone::LeftMiddleDistal
+            | Bone::LeftRingProximal
+            | Bone::LeftRingIntermediate
+            | Bone::LeftRingDistal
+            | Bone::LeftLittleProximal
+            | Bone::LeftLittleIntermediate
+            | Bone::LeftLittleDistal => HumanBoneGroup::LeftFingers,
+            Bone::RightThumbProximal
+            | Bone::RightThumbIntermediate
+            | Bone::RightThumbDistal
+            | Bone::RightIndexProximal
+            | Bone::RightIndexIntermediate
+            | Bone::RightIndexDistal
+            | Bone::RightMiddleProximal
+            | Bone::RightMiddleIntermediate
+            | Bone::RightMiddleDistal
+            | Bone::RightRingProximal
+            | Bone::RightRingIntermediate
+            | Bone::RightRingDistal
+            | Bone::RightLittleProximal
+            | Bone::RightLittleIntermediate
+            | Bone::RightLittleDistal => HumanBoneGroup::RightFingers,
+        }
+    }
+
+    /// Returns the side of the body, or `None` for the bones on the center line
+    pub const fn side(self) -> Option<HumanBoneSide> {
+        match self {
+            Bone::Hips
+            | Bone::Spine
+            | Bone::Chest
+            | Bone::UpperChest
+            | Bone::Neck
+            | Bone::Head
+            | Bone::Jaw => None,
+            Bone::LeftEye
+            | Bone::LeftUpperLeg
+            | Bone::LeftLowerLeg
+            | Bone::LeftFoot
+            | Bone::LeftToes
+            | Bone::LeftShoulder
+            | Bone::LeftUpperArm
+            | Bone::LeftLowerArm
+            | Bone::LeftHand
+            | Bone::LeftThumbProximal
+            | Bone::LeftThumbIntermediate
+            | Bone::LeftThumbDistal
+            | Bone::LeftIndexProximal
+            | Bone::LeftIndexIntermediate
+            | Bone::LeftIndexDistal
+            | Bone::LeftMiddleProximal
+            | Bone::LeftMiddleIntermediate
+            | Bone::LeftMiddleDistal
+            | Bone::LeftRingProximal
+            | Bone::LeftRingIntermediate
+            | Bone::LeftRingDistal
+            | Bone::LeftLittleProximal
+            | Bone::LeftLittleIntermediate
+            | Bone::LeftLittleDistal => Some(HumanBoneSide::Left),
+            Bone::RightEye
+            | Bone::RightUpperLeg
+            | Bone::RightLowerLeg
+            | Bone::RightFoot
+            | Bone::RightToes
+            | Bone::RightShoulder
+            | Bone::RightUpperArm
+            | Bone::RightLowerArm
+            | Bone::RightHand
+            | Bone::RightThumbProximal
+            | Bone::RightThumbIntermediate
+            | Bone::RightThumbDistal
+            | Bone::RightIndexProximal
+            | Bone::RightIndexIntermediate
+            | Bone::RightIndexDistal
+            | Bone::RightMiddleProximal
+            | Bone::RightMiddleIntermediate
+            | Bone::RightMiddleDistal
+            | Bone::RightRingProximal
+            | Bone::RightRingIntermediate
+            | Bone::RightRingDistal
+            | Bone::RightLittleProximal
+            | Bone::RightLittleIntermediate
+            | Bone::RightLittleDistal => Some(HumanBoneSide::Right),
+        }
+    }
+
+    /// Returns the bone on the opposite side, e.g. `rightHand` for `leftHand`. The bones on the
+    /// center line are mirrored to themselves.
+    pub const fn mirror(self) -> Bone {
+        match self {
+            Bone::LeftEye => Bone::RightEye,
+            Bone::RightEye => Bone::LeftEye,
+            Bone::LeftUpperLeg => Bone::RightUpperLeg,
+            Bone::LeftLowerLeg => Bone::RightLowerLeg,
+            Bone::LeftFoot => Bone::RightFoot,
+            Bone::LeftToes => Bone::RightToes,
+            Bone::RightUpperLeg => Bone::LeftUpperLeg,
+            Bone::RightLowerLeg => Bone::LeftLowerLeg,
+            Bone::RightFoot => Bone::LeftFoot,
+            Bone::RightToes => Bone::LeftToes,
+            Bone::LeftShoulder => Bone::RightShoulder,
+            Bone::LeftUpperArm => Bone::RightUpperArm,
+            Bone::LeftLowerArm => Bone::RightLowerArm,
+            Bone::LeftHand => Bone::RightHand,
+            Bone::RightShoulder => Bone::LeftShoulder,
+            Bone::RightUpperArm => Bone::LeftUpperArm,
+            Bone::RightLowerArm => Bone::LeftLowerArm,
+            Bone::RightHand => Bone::LeftHand,
+            Bone::LeftThumbProximal => Bone::RightThumbProximal,
+            Bone::LeftThumbIntermediate => Bone::RightThumbIntermediate,
+            Bone::LeftThumbDistal => Bone::RightThumbDistal,
+            Bone::LeftIndexProximal => Bone::RightIndexProximal,
+            Bone::LeftIndexIntermediate => Bone::RightIndexIntermediate,
+            Bone::LeftIndexDistal => Bone::RightIndexDistal,
+            Bone::LeftMiddleProximal => Bone::RightMiddleProximal,
+            Bone::LeftMiddleIntermediate => Bone::RightMiddleIntermediate,
+            Bone::LeftMiddleDistal => Bone::RightMiddleDistal,
+            Bone::LeftRingProximal => Bone::RightRingProximal,
+            Bone::LeftRingIntermediate => Bone::RightRingIntermediate,
+            Bone::LeftRingDistal => Bone::RightRingDistal,
+            Bone::LeftLittleProximal => Bone::RightLittleProximal,
+            Bone::LeftLittleIntermediate => Bone::RightLittleIntermediate,
+            Bone::LeftLittleDistal => Bone::RightLittleDistal,
+            Bone::RightThumbProximal => Bone::LeftThumbProximal,
+            Bone::RightThumbIntermediate => Bone::LeftThumbIntermediate,
+            Bone::RightThumbDistal => Bone::LeftThumbDistal,
+            Bone::RightIndexProximal => Bone::LeftIndexProximal,
+            Bone::RightIndexIntermediate => Bone::LeftIndexIntermediate,
+            Bone::RightIndexDistal => Bone::LeftIndexDistal,
+            Bone::RightMiddleProximal => Bone::LeftMiddleProximal,
+            Bone::RightMiddleIntermediate => Bone::LeftMiddleIntermediate,
+            Bone::RightMiddleDistal => Bone::LeftMiddleDistal,
+            Bone::RightRingProximal => Bone::LeftRingProximal,
+            Bone::RightRingIntermediate => Bone::LeftRingIntermediate,
+            Bone::RightRingDistal => Bone::LeftRingDistal,
+            Bone::RightLittleProximal => Bone::LeftLittleProximal,
+            Bone::RightLittleIntermediate => Bone::LeftLittleIntermediate,
+            Bone::RightLittleDistal => Bone::LeftLittleDistal,
+            Bone::Hips
+            | Bone::Spine
+            | Bone::Chest
+            | Bone::UpperChest
+            | Bone::Neck
+            | Bone::Head
+            | Bone::Jaw => self,
+        }
+    }
+}
+
 /// A person who can perform with this avatar.
 #[derive(Debug, Clone, Copy, Hash, Serialize, Deserialize)]
 pub enum AllowedUserName {
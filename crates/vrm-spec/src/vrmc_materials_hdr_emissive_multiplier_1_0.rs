@@ -0,0 +1,88 @@
+//! Data structures for the [`VRMC_materials_hdr_emissiveMultiplier`](https://github.com/vrm-c/vrm-specification/tree/master/specification/VRMC_materials_hdr_emissiveMultiplier-1.0) 1.0 glTF Extension.
+
+#[cfg(feature = "rustc_hash")]
+use rustc_hash::FxHashMap as HashMap;
+use serde::{Deserialize, Serialize};
+#[cfg(not(feature = "rustc_hash"))]
+use std::collections::HashMap;
+
+/// VRMC_materials_hdr_emissiveMultiplier extension name
+pub const VRMC_MATERIALS_HDR_EMISSIVE_MULTIPLIER: &str = "VRMC_materials_hdr_emissiveMultiplier";
+
+/// KHR_materials_emissive_strength extension name
+pub const KHR_MATERIALS_EMISSIVE_STRENGTH: &str = "KHR_materials_emissive_strength";
+
+/// Multiplies the emissive color of a material to express HDR emission
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VrmcMaterialsHdrEmissiveMultiplierSchema {
+    /// The multiplier applied to the emissive color
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emissive_multiplier: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extensions: Option<HashMap<String, HashMap<String, Option<serde_json::Value>>>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extras: Option<serde_json::Value>,
+
+    /// Specification version of VRMC_materials_hdr_emissiveMultiplier
+    pub spec_version: String,
+
+    /// Properties which are not defined in the schema
+    #[cfg(feature = "preserve_unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Returns the `VRMC_materials_hdr_emissiveMultiplier` extension of the material if present.
+#[cfg(feature = "gltf_index")]
+pub fn hdr_emissive_multiplier(
+    material: &gltf::json::Material,
+) -> Option<Result<VrmcMaterialsHdrEmissiveMultiplierSchema, crate::Error>> {
+    let value = material
+        .extensions
+        .as_ref()?
+        .others
+        .get(VRMC_MATERIALS_HDR_EMISSIVE_MULTIPLIER)?;
+    Some(crate::error::from_value_at(
+        value,
+        &format!("/extensions/{}", VRMC_MATERIALS_HDR_EMISSIVE_MULTIPLIER),
+    ))
+}
+
+/// Returns the emissive factor of the material with the HDR multipliers applied.
+///
+/// `emissiveFactor` is scaled by both `emissiveStrength` of `KHR_materials_emissive_strength` and
+/// `emissiveMultiplier` of `VRMC_materials_hdr_emissiveMultiplier`. A missing extension or
+/// property counts as 1.
+#[cfg(feature = "gltf_index")]
+pub fn effective_emissive_factor(
+    material: &gltf::json::Material,
+) -> Result<[f32; 3], crate::Error> {
+    // NOTE: `KHR_materials_emissive_strength` lives either in a dedicated field or in `others`
+    // depending on the features of `gltf-json`, so read it from the serialized value
+    let extensions = serde_json::to_value(&material.extensions).map_err(crate::Error::Serialize)?;
+    let strength = match extensions
+        .get(KHR_MATERIALS_EMISSIVE_STRENGTH)
+        .and_then(|extension| extension.get("emissiveStrength"))
+    {
+        Some(emissive_strength) => crate::error::from_value_at(
+            emissive_strength,
+            &format!(
+                "/extensions/{}/emissiveStrength",
+                KHR_MATERIALS_EMISSIVE_STRENGTH
+            ),
+        )?,
+        None => 1.0,
+    };
+    let multiplier = match hdr_emissive_multiplier(material).transpose()? {
+        Some(extension) => extension.emissive_multiplier.unwrap_or(1.0),
+        None => 1.0,
+    };
+
+    Ok(material
+        .emissive_factor
+        .0
+        .map(|c| c * (strength * multiplier) as f32))
+}
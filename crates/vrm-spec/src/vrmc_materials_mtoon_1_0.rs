@@ -97,6 +97,11 @@ pub struct VrmcMaterialsMtoonSchema {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub uv_animation_scroll_y_speed_factor: Option<f64>,
+
+    /// Properties which are not defined in the schema
+    #[cfg(feature = "preserve_unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Reference to a texture.
@@ -119,6 +124,11 @@ pub struct TextureInfo {
     #[cfg(not(feature = "gltf_index"))]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tex_coord: Option<usize>,
+
+    /// Properties which are not defined in the schema
+    #[cfg(feature = "preserve_unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Reference to a texture.
@@ -148,6 +158,11 @@ pub struct ShadingShiftTextureInfo {
     #[cfg(not(feature = "gltf_index"))]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tex_coord: Option<usize>,
+
+    /// Properties which are not defined in the schema
+    #[cfg(feature = "preserve_unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Outline
@@ -0,0 +1,164 @@
+//! Data structures for the [`VRMC_node_constraint`](https://github.com/vrm-c/vrm-specification/tree/master/specification/VRMC_node_constraint-1.0) 1.0 glTF Extension.
+//!
+//! Unlike the other VRM extensions, this one is attached to each `gltf::json::Node` rather than
+//! to the root of the glTF document.
+
+#[cfg(feature = "rustc_hash")]
+use rustc_hash::FxHashMap as HashMap;
+use serde::{Deserialize, Serialize};
+#[cfg(not(feature = "rustc_hash"))]
+use std::collections::HashMap;
+
+/// VRMC_node_constraint extension name
+pub const VRMC_NODE_CONSTRAINT: &str = "VRMC_node_constraint";
+
+/// Constraint definition of a node
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VrmcNodeConstraintSchema {
+    /// Contains roll, aim, or rotation
+    pub constraint: Constraint,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extensions: Option<HashMap<String, HashMap<String, Option<serde_json::Value>>>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extras: Option<serde_json::Value>,
+
+    /// Specification version of VRMC_node_constraint
+    pub spec_version: String,
+
+    /// Properties which are not defined in the schema
+    #[cfg(feature = "preserve_unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Contains roll, aim, or rotation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Constraint {
+    /// Aim constraint
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aim: Option<AimConstraint>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extensions: Option<HashMap<String, HashMap<String, Option<serde_json::Value>>>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extras: Option<serde_json::Value>,
+
+    /// Roll constraint
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub roll: Option<RollConstraint>,
+
+    /// Rotation constraint
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rotation: Option<RotationConstraint>,
+
+    /// Properties which are not defined in the schema
+    #[cfg(feature = "preserve_unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Transfers the rotation of the source node around a specified axis to the destination node.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RollConstraint {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extensions: Option<HashMap<String, HashMap<String, Option<serde_json::Value>>>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extras: Option<serde_json::Value>,
+
+    /// The roll axis of the constraint.
+    pub roll_axis: RollAxis,
+
+    /// The index of the node constrains the node.
+    #[cfg(feature = "gltf_index")]
+    pub source: gltf::json::Index<gltf::json::Node>,
+    #[cfg(not(feature = "gltf_index"))]
+    pub source: usize,
+
+    /// The weight of the constraint.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weight: Option<f64>,
+
+    /// Properties which are not defined in the schema
+    #[cfg(feature = "preserve_unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Makes the destination node face the source node.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AimConstraint {
+    /// The aim axis of the constraint.
+    pub aim_axis: AimAxis,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extensions: Option<HashMap<String, HashMap<String, Option<serde_json::Value>>>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extras: Option<serde_json::Value>,
+
+    /// The index of the node constrains the node.
+    #[cfg(feature = "gltf_index")]
+    pub source: gltf::json::Index<gltf::json::Node>,
+    #[cfg(not(feature = "gltf_index"))]
+    pub source: usize,
+
+    /// The weight of the constraint.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weight: Option<f64>,
+
+    /// Properties which are not defined in the schema
+    #[cfg(feature = "preserve_unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Transfers the rotation of the source node to the destination node.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RotationConstraint {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extensions: Option<HashMap<String, HashMap<String, Option<serde_json::Value>>>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extras: Option<serde_json::Value>,
+
+    /// The index of the node constrains the node.
+    #[cfg(feature = "gltf_index")]
+    pub source: gltf::json::Index<gltf::json::Node>,
+    #[cfg(not(feature = "gltf_index"))]
+    pub source: usize,
+
+    /// The weight of the constraint.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weight: Option<f64>,
+
+    /// Properties which are not defined in the schema
+    #[cfg(feature = "preserve_unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, serde_json::Value>,
+}
+
+/// The roll axis of the constraint.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RollAxis {
+    X,
+    Y,
+    Z,
+}
+
+/// The aim axis of the constraint.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AimAxis {
+    PositiveX,
+    NegativeX,
+    PositiveY,
+    NegativeY,
+    PositiveZ,
+    NegativeZ,
+}
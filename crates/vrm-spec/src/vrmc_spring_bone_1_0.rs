@@ -1,11 +1,18 @@
 //! Data structures for the [`VRMC_springBone`](https://github.com/vrm-c/vrm-specification/tree/master/specification/VRMC_springBone-1.0) 1.0 glTF Extension.
 
+use std::fmt;
+
 #[cfg(feature = "rustc_hash")]
 use rustc_hash::FxHashMap as HashMap;
 use serde::{Deserialize, Serialize};
 #[cfg(not(feature = "rustc_hash"))]
 use std::collections::HashMap;
 
+use crate::serde_utils::deserialize_extension;
+use crate::vrmc_spring_bone_extended_collider_1_0::{
+    ExtendedColliderShape, VrmcSpringBoneExtendedColliderSchema, VRMC_SPRING_BONE_EXTENDED_COLLIDER,
+};
+
 /// VRMC_springBone extension name
 pub const VRMC_SPRING_BONE: &str = "VRMC_springBone";
 
@@ -33,6 +40,11 @@ pub struct VrmcSpringBoneSchema {
     /// An array of springs.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub springs: Option<Vec<Spring>>,
+
+    /// Properties which are not defined in the schema
+    #[cfg(feature = "preserve_unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, serde_json::Value>,
 }
 
 /// collider group definition for SpringBone
@@ -50,6 +62,11 @@ pub struct ColliderGroup {
     /// Name of the ColliderGroup
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
+
+    /// Properties which are not defined in the schema
+    #[cfg(feature = "preserve_unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, serde_json::Value>,
 }
 
 /// collider definition for SpringBone
@@ -68,6 +85,38 @@ pub struct Collider {
     pub node: usize,
 
     pub shape: ColliderShape,
+
+    /// Properties which are not defined in the schema
+    #[cfg(feature = "preserve_unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, serde_json::Value>,
+}
+
+impl Collider {
+    /// Returns the `VRMC_springBone_extended_collider` extension of the collider if present.
+    pub fn extended_collider(
+        &self,
+    ) -> Option<Result<VrmcSpringBoneExtendedColliderSchema, crate::Error>> {
+        deserialize_extension(self.extensions.as_ref(), VRMC_SPRING_BONE_EXTENDED_COLLIDER)
+    }
+
+    /// Returns the shape of the collider, preferring the extended shape of
+    /// `VRMC_springBone_extended_collider` over the standard one used as a fallback.
+    pub fn effective_shape(&self) -> Result<EffectiveColliderShape, crate::Error> {
+        Ok(match self.extended_collider().transpose()? {
+            Some(extended_collider) => EffectiveColliderShape::Extended(extended_collider.shape),
+            None => EffectiveColliderShape::Standard(self.shape.clone()),
+        })
+    }
+}
+
+/// Shape of collider resolved by [`Collider::effective_shape`]
+#[derive(Debug, Clone)]
+pub enum EffectiveColliderShape {
+    /// The shape of `VRMC_springBone_extended_collider`
+    Extended(ExtendedColliderShape),
+    /// The standard shape of `VRMC_springBone`
+    Standard(ColliderShape),
 }
 
 /// Shape of collider. Have one of sphere and capsule
@@ -84,8 +133,105 @@ pub struct ColliderShape {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sphere: Option<ColliderShapeSphere>,
+
+    /// Properties which are not defined in the schema
+    #[cfg(feature = "preserve_unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, serde_json::Value>,
+}
+
+impl ColliderShape {
+    /// Returns the shape with the defaults of the specification applied to the missing
+    /// properties. Fails if the shape has none or both of `sphere` and `capsule`.
+    pub fn kind(&self) -> Result<ColliderShapeKind, ColliderShapeError> {
+        match (&self.sphere, &self.capsule) {
+            (Some(sphere), None) => Ok(ColliderShapeKind::Sphere {
+                offset: sphere.offset.unwrap_or_default(),
+                radius: sphere.radius.unwrap_or_default(),
+            }),
+            (None, Some(capsule)) => Ok(ColliderShapeKind::Capsule {
+                offset: capsule.offset.unwrap_or_default(),
+                radius: capsule.radius.unwrap_or_default(),
+                tail: capsule.tail.unwrap_or_default(),
+            }),
+            (None, None) => Err(ColliderShapeError {
+                reason: "neither sphere nor capsule is given",
+            }),
+            (Some(_), Some(_)) => Err(ColliderShapeError {
+                reason: "both sphere and capsule are given",
+            }),
+        }
+    }
 }
 
+impl From<ColliderShapeKind> for ColliderShape {
+    fn from(kind: ColliderShapeKind) -> Self {
+        let (sphere, capsule) = match kind {
+            ColliderShapeKind::Sphere { offset, radius } => (
+                Some(ColliderShapeSphere {
+                    offset: Some(offset),
+                    radius: Some(radius),
+                }),
+                None,
+            ),
+            ColliderShapeKind::Capsule {
+                offset,
+                radius,
+                tail,
+            } => (
+                None,
+                Some(ColliderShapeCapsule {
+                    offset: Some(offset),
+                    radius: Some(radius),
+                    tail: Some(tail),
+                }),
+            ),
+        };
+        ColliderShape {
+            capsule,
+            extensions: None,
+            extras: None,
+            sphere,
+            #[cfg(feature = "preserve_unknown_fields")]
+            unknown_fields: Default::default(),
+        }
+    }
+}
+
+/// Shape of collider resolved by [`ColliderShape::kind`], with the defaults applied
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ColliderShapeKind {
+    Sphere {
+        /// The sphere center. vector3
+        offset: [f64; 3],
+        /// The sphere radius
+        radius: f64,
+    },
+    Capsule {
+        /// The capsule head. vector3
+        offset: [f64; 3],
+        /// The capsule radius
+        radius: f64,
+        /// The capsule tail. vector3
+        tail: [f64; 3],
+    },
+}
+
+/// A collider shape which has none or both of `sphere` and `capsule`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColliderShapeError {
+    /// Why the shape is invalid, e.g. `both sphere and capsule are given`
+    pub reason: &'static str,
+}
+
+impl fmt::Display for ColliderShapeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid collider shape: {}", self.reason)
+    }
+}
+
+impl std::error::Error for ColliderShapeError {}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct ColliderShapeCapsule {
     /// The capsule head. vector3
@@ -139,6 +285,11 @@ pub struct Spring {
     /// Name of the Spring
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
+
+    /// Properties which are not defined in the schema
+    #[cfg(feature = "preserve_unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A bone joint of VRMCSpringBone.
@@ -176,4 +327,9 @@ pub struct SpringBoneJoint {
     /// The force to return to the initial pose.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub stiffness: Option<f64>,
+
+    /// Properties which are not defined in the schema
+    #[cfg(feature = "preserve_unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, serde_json::Value>,
 }
@@ -0,0 +1,110 @@
+//! Data structures for the [`VRMC_springBone_extended_collider`](https://github.com/vrm-c/vrm-specification/tree/master/specification/VRMC_springBone_extended_collider-1.0) 1.0 glTF Extension.
+//!
+//! This extension is stored in
+//! [`Collider::extensions`](crate::vrmc_spring_bone_1_0::Collider::extensions) of `VRMC_springBone`.
+//! The standard [`ColliderShape`](crate::vrmc_spring_bone_1_0::ColliderShape) of the collider is
+//! used as a fallback by implementations which don't support this extension.
+
+#[cfg(feature = "rustc_hash")]
+use rustc_hash::FxHashMap as HashMap;
+use serde::{Deserialize, Serialize};
+#[cfg(not(feature = "rustc_hash"))]
+use std::collections::HashMap;
+
+/// VRMC_springBone_extended_collider extension name
+pub const VRMC_SPRING_BONE_EXTENDED_COLLIDER: &str = "VRMC_springBone_extended_collider";
+
+/// Extended collider definition for SpringBone
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VrmcSpringBoneExtendedColliderSchema {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extensions: Option<HashMap<String, HashMap<String, Option<serde_json::Value>>>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extras: Option<serde_json::Value>,
+
+    pub shape: ExtendedColliderShape,
+
+    /// Specification version of VRMC_springBone_extended_collider
+    pub spec_version: String,
+
+    /// Properties which are not defined in the schema
+    #[cfg(feature = "preserve_unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Shape of extended collider. Have one of sphere, capsule and plane
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExtendedColliderShape {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capsule: Option<ExtendedColliderShapeCapsule>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extensions: Option<HashMap<String, HashMap<String, Option<serde_json::Value>>>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extras: Option<serde_json::Value>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub plane: Option<ExtendedColliderShapePlane>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sphere: Option<ExtendedColliderShapeSphere>,
+
+    /// Properties which are not defined in the schema
+    #[cfg(feature = "preserve_unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct ExtendedColliderShapeCapsule {
+    /// If true, the collider prevents spring bones from going outside of the capsule instead.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inside: Option<bool>,
+
+    /// The capsule head. vector3
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<[f64; 3]>,
+
+    /// The capsule radius
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub radius: Option<f64>,
+
+    /// The capsule tail. vector3
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tail: Option<[f64; 3]>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExtendedColliderShapePlane {
+    /// The plane normal. vector3
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub normal: Option<[f64; 3]>,
+
+    /// The plane origin. vector3
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<[f64; 3]>,
+
+    /// Properties which are not defined in the schema
+    #[cfg(feature = "preserve_unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct ExtendedColliderShapeSphere {
+    /// If true, the collider prevents spring bones from going outside of the sphere instead.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inside: Option<bool>,
+
+    /// The sphere center. vector3
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<[f64; 3]>,
+
+    /// The sphere radius
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub radius: Option<f64>,
+}
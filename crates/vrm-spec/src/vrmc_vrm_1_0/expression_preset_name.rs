@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use crate::vrm_0_0::PresetName;
+
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum ExpressionPresetName {
@@ -22,3 +24,137 @@ pub enum ExpressionPresetName {
     Sad,
     Surprised,
 }
+
+impl ExpressionPresetName {
+    /// All the presets in the order of the specification
+    pub const ALL: [ExpressionPresetName; 18] = [
+        ExpressionPresetName::Happy,
+        ExpressionPresetName::Angry,
+        ExpressionPresetName::Sad,
+        ExpressionPresetName::Relaxed,
+        ExpressionPresetName::Surprised,
+        ExpressionPresetName::Aa,
+        ExpressionPresetName::Ih,
+        ExpressionPresetName::Ou,
+        ExpressionPresetName::Ee,
+        ExpressionPresetName::Oh,
+        ExpressionPresetName::Blink,
+        ExpressionPresetName::BlinkLeft,
+        ExpressionPresetName::BlinkRight,
+        ExpressionPresetName::LookUp,
+        ExpressionPresetName::LookDown,
+        ExpressionPresetName::LookLeft,
+        ExpressionPresetName::LookRight,
+        ExpressionPresetName::Neutral,
+    ];
+
+    /// Returns the category of the preset. The blink, lookAt and lip-sync presets are the ones
+    /// which `overrideBlink`, `overrideLookAt` and `overrideMouth` of an expression apply to.
+    pub const fn category(self) -> ExpressionCategory {
+        match self {
+            ExpressionPresetName::Happy
+            | ExpressionPresetName::Angry
+            | ExpressionPresetName::Sad
+            | ExpressionPresetName::Relaxed
+            | ExpressionPresetName::Surprised => ExpressionCategory::Emotion,
+            ExpressionPresetName::Aa
+            | ExpressionPresetName::Ih
+            | ExpressionPresetName::Ou
+            | ExpressionPresetName::Ee
+            | ExpressionPresetName::Oh => ExpressionCategory::LipSync,
+            ExpressionPresetName::Blink
+            | ExpressionPresetName::BlinkLeft
+            | ExpressionPresetName::BlinkRight => ExpressionCategory::Blink,
+            ExpressionPresetName::LookUp
+            | ExpressionPresetName::LookDown
+            | ExpressionPresetName::LookLeft
+            | ExpressionPresetName::LookRight => ExpressionCategory::LookAt,
+            ExpressionPresetName::Neutral => ExpressionCategory::Other,
+        }
+    }
+
+    /// Iterates the presets of `category` in the order of [`ALL`](Self::ALL)
+    pub fn in_category(category: ExpressionCategory) -> impl Iterator<Item = Self> {
+        Self::ALL
+            .into_iter()
+            .filter(move |name| name.category() == category)
+    }
+}
+
+impl TryFrom<PresetName> for ExpressionPresetName {
+    type Error = PresetName;
+
+    /// Converts a preset of a blend shape group of VRM 0.x, e.g. `joy` to `happy` and `a` to `aa`.
+    ///
+    /// Fails with [`PresetName::Unknown`], which marks a custom blend shape group. Such a group is
+    /// a custom expression of VRM 1.0 named after the name of the group.
+    fn try_from(name: PresetName) -> Result<Self, Self::Error> {
+        Ok(match name {
+            PresetName::A => ExpressionPresetName::Aa,
+            PresetName::Angry => ExpressionPresetName::Angry,
+            PresetName::Blink => ExpressionPresetName::Blink,
+            PresetName::BlinkL => ExpressionPresetName::BlinkLeft,
+            PresetName::BlinkR => ExpressionPresetName::BlinkRight,
+            PresetName::E => ExpressionPresetName::Ee,
+            PresetName::Fun => ExpressionPresetName::Relaxed,
+            PresetName::I => ExpressionPresetName::Ih,
+            PresetName::Joy => ExpressionPresetName::Happy,
+            PresetName::Lookdown => ExpressionPresetName::LookDown,
+            PresetName::Lookleft => ExpressionPresetName::LookLeft,
+            PresetName::Lookright => ExpressionPresetName::LookRight,
+            PresetName::Lookup => ExpressionPresetName::LookUp,
+            PresetName::Neutral => ExpressionPresetName::Neutral,
+            PresetName::O => ExpressionPresetName::Oh,
+            PresetName::Sorrow => ExpressionPresetName::Sad,
+            PresetName::U => ExpressionPresetName::Ou,
+            PresetName::Unknown => return Err(name),
+        })
+    }
+}
+
+impl TryFrom<ExpressionPresetName> for PresetName {
+    type Error = ExpressionPresetName;
+
+    /// Converts to a preset of a blend shape group of VRM 0.x, e.g. `happy` to `joy` and `aa` to
+    /// `a`.
+    ///
+    /// Fails with [`ExpressionPresetName::Surprised`], which VRM 0.x has no preset for. Such an
+    /// expression is a blend shape group of [`PresetName::Unknown`] named `surprised`.
+    fn try_from(name: ExpressionPresetName) -> Result<Self, Self::Error> {
+        Ok(match name {
+            ExpressionPresetName::Aa => PresetName::A,
+            ExpressionPresetName::Angry => PresetName::Angry,
+            ExpressionPresetName::Blink => PresetName::Blink,
+            ExpressionPresetName::BlinkLeft => PresetName::BlinkL,
+            ExpressionPresetName::BlinkRight => PresetName::BlinkR,
+            ExpressionPresetName::Ee => PresetName::E,
+            ExpressionPresetName::Happy => PresetName::Joy,
+            ExpressionPresetName::Ih => PresetName::I,
+            ExpressionPresetName::LookDown => PresetName::Lookdown,
+            ExpressionPresetName::LookLeft => PresetName::Lookleft,
+            ExpressionPresetName::LookRight => PresetName::Lookright,
+            ExpressionPresetName::LookUp => PresetName::Lookup,
+            ExpressionPresetName::Neutral => PresetName::Neutral,
+            ExpressionPresetName::Oh => PresetName::O,
+            ExpressionPresetName::Ou => PresetName::U,
+            ExpressionPresetName::Relaxed => PresetName::Fun,
+            ExpressionPresetName::Sad => PresetName::Sorrow,
+            ExpressionPresetName::Surprised => return Err(name),
+        })
+    }
+}
+
+/// Category of an expression preset
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum ExpressionCategory {
+    /// `happy`, `angry`, `sad`, `relaxed` and `surprised`
+    Emotion,
+    /// `aa`, `ih`, `ou`, `ee` and `oh`, which `overrideMouth` applies to
+    LipSync,
+    /// `blink`, `blinkLeft` and `blinkRight`, which `overrideBlink` applies to
+    Blink,
+    /// `lookUp`, `lookDown`, `lookLeft` and `lookRight`, which `overrideLookAt` applies to
+    LookAt,
+    /// `neutral`
+    Other,
+}
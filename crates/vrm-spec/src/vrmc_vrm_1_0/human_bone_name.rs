@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use crate::vrm_0_0::Bone;
+
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum HumanBoneName {
@@ -59,3 +61,485 @@ pub enum HumanBoneName {
     Spine,
     UpperChest,
 }
+
+impl HumanBoneName {
+    /// All the bones in the order of the hierarchy, where a parent comes before its children
+    pub const ALL: [HumanBoneName; 55] = [
+        HumanBoneName::Hips,
+        HumanBoneName::Spine,
+        HumanBoneName::Chest,
+        HumanBoneName::UpperChest,
+        HumanBoneName::Neck,
+        HumanBoneName::Head,
+        HumanBoneName::LeftEye,
+        HumanBoneName::RightEye,
+        HumanBoneName::Jaw,
+        HumanBoneName::LeftUpperLeg,
+        HumanBoneName::LeftLowerLeg,
+        HumanBoneName::LeftFoot,
+        HumanBoneName::LeftToes,
+        HumanBoneName::RightUpperLeg,
+        HumanBoneName::RightLowerLeg,
+        HumanBoneName::RightFoot,
+        HumanBoneName::RightToes,
+        HumanBoneName::LeftShoulder,
+        HumanBoneName::LeftUpperArm,
+        HumanBoneName::LeftLowerArm,
+        HumanBoneName::LeftHand,
+        HumanBoneName::RightShoulder,
+        HumanBoneName::RightUpperArm,
+        HumanBoneName::RightLowerArm,
+        HumanBoneName::RightHand,
+        HumanBoneName::LeftThumbMetacarpal,
+        HumanBoneName::LeftThumbProximal,
+        HumanBoneName::LeftThumbDistal,
+        HumanBoneName::LeftIndexProximal,
+        HumanBoneName::LeftIndexIntermediate,
+        HumanBoneName::LeftIndexDistal,
+        HumanBoneName::LeftMiddleProximal,
+        HumanBoneName::LeftMiddleIntermediate,
+        HumanBoneName::LeftMiddleDistal,
+        HumanBoneName::LeftRingProximal,
+        HumanBoneName::LeftRingIntermediate,
+        HumanBoneName::LeftRingDistal,
+        HumanBoneName::LeftLittleProximal,
+        HumanBoneName::LeftLittleIntermediate,
+        HumanBoneName::LeftLittleDistal,
+        HumanBoneName::RightThumbMetacarpal,
+        HumanBoneName::RightThumbProximal,
+        HumanBoneName::RightThumbDistal,
+        HumanBoneName::RightIndexProximal,
+        HumanBoneName::RightIndexIntermediate,
+        HumanBoneName::RightIndexDistal,
+        HumanBoneName::RightMiddleProximal,
+        HumanBoneName::RightMiddleIntermediate,
+        HumanBoneName::RightMiddleDistal,
+        HumanBoneName::RightRingProximal,
+        HumanBoneName::RightRingIntermediate,
+        HumanBoneName::RightRingDistal,
+        HumanBoneName::RightLittleProximal,
+        HumanBoneName::RightLittleIntermediate,
+        HumanBoneName::RightLittleDistal,
+    ];
+
+    /// Returns the parent bone in the hierarchy of the specification, e.g. `leftUpperArm` for
+    /// `leftLowerArm`. If the parent is optional and missing in a humanoid, the nearest ancestor
+    /// which exists takes its place.
+    pub const fn parent(self) -> Option<HumanBoneName> {
+        match self {
+            HumanBoneName::Hips => None,
+            HumanBoneName::Spine | HumanBoneName::LeftUpperLeg | HumanBoneName::RightUpperLeg => {
+                Some(HumanBoneName::Hips)
+            }
+            HumanBoneName::Chest => Some(HumanBoneName::Spine),
+            HumanBoneName::UpperChest => Some(HumanBoneName::Chest),
+            HumanBoneName::Neck | HumanBoneName::LeftShoulder | HumanBoneName::RightShoulder => {
+                Some(HumanBoneName::UpperChest)
+            }
+            HumanBoneName::Head => Some(HumanBoneName::Neck),
+            HumanBoneName::LeftEye | HumanBoneName::RightEye | HumanBoneName::Jaw => {
+                Some(HumanBoneName::Head)
+            }
+            HumanBoneName::LeftLowerLeg => Some(HumanBoneName::LeftUpperLeg),
+            HumanBoneName::LeftFoot => Some(HumanBoneName::LeftLowerLeg),
+            HumanBoneName::LeftToes => Some(HumanBoneName::LeftFoot),
+            HumanBoneName::RightLowerLeg => Some(HumanBoneName::RightUpperLeg),
+            HumanBoneName::RightFoot => Some(HumanBoneName::RightLowerLeg),
+            HumanBoneName::RightToes => Some(HumanBoneName::RightFoot),
+            HumanBoneName::LeftUpperArm => Some(HumanBoneName::LeftShoulder),
+            HumanBoneName::LeftLowerArm => Some(HumanBoneName::LeftUpperArm),
+            HumanBoneName::LeftHand => Some(HumanBoneName::LeftLowerArm),
+            HumanBoneName::RightUpperArm => Some(HumanBoneName::RightShoulder),
+            HumanBoneName::RightLowerArm => Some(HumanBoneName::RightUpperArm),
+            HumanBoneName::RightHand => Some(HumanBoneName::RightLowerArm),
+            HumanBoneName::LeftThumbMetacarpal
+            | HumanBoneName::LeftIndexProximal
+            | HumanBoneName::LeftMiddleProximal
+            | HumanBoneName::LeftRingProximal
+            | HumanBoneName::LeftLittleProximal => Some(HumanBoneName::LeftHand),
+            HumanBoneName::LeftThumbProximal => Some(HumanBoneName::LeftThumbMetacarpal),
+            HumanBoneName::LeftThumbDistal => Some(HumanBoneName::LeftThumbProximal),
+            HumanBoneName::LeftIndexIntermediate => Some(HumanBoneName::LeftIndexProximal),
+            HumanBoneName::LeftIndexDistal => Some(HumanBoneName::LeftIndexIntermediate),
+            HumanBoneName::LeftMiddleIntermediate => Some(HumanBoneName::LeftMiddleProximal),
+            HumanBoneName::LeftMiddleDistal => Some(HumanBoneName::LeftMiddleIntermediate),
+            HumanBoneName::LeftRingIntermediate => Some(HumanBoneName::LeftRingProximal),
+            HumanBoneName::LeftRingDistal => Some(HumanBoneName::LeftRingIntermediate),
+            HumanBoneName::LeftLittleIntermediate => Some(HumanBoneName::LeftLittleProximal),
+            HumanBoneName::LeftLittleDistal => Some(HumanBoneName::LeftLittleIntermediate),
+            HumanBoneName::RightThumbMetacarpal
+            | HumanBoneName::RightIndexProximal
+            | HumanBoneName::RightMiddleProximal
+            | HumanBoneName::RightRingProximal
+            | HumanBoneName::RightLittleProximal => Some(HumanBoneName::RightHand),
+            HumanBoneName::RightThumbProximal => Some(HumanBoneName::RightThumbMetacarpal),
+            HumanBoneName::RightThumbDistal => Some(HumanBoneName::RightThumbProximal),
+            HumanBoneName::RightIndexIntermediate => Some(HumanBoneName::RightIndexProximal),
+            HumanBoneName::RightIndexDistal => Some(HumanBoneName::RightIndexIntermediate),
+            HumanBoneName::RightMiddleIntermediate => Some(HumanBoneName::RightMiddleProximal),
+            HumanBoneName::RightMiddleDistal => Some(HumanBoneName::RightMiddleIntermediate),
+            HumanBoneName::RightRingIntermediate => Some(HumanBoneName::RightRingProximal),
+            HumanBoneName::RightRingDistal => Some(HumanBoneName::RightRingIntermediate),
+            HumanBoneName::RightLittleIntermediate => Some(HumanBoneName::RightLittleProximal),
+            HumanBoneName::RightLittleDistal => Some(HumanBoneName::RightLittleIntermediate),
+        }
+    }
+
+    /// Returns `true` if a humanoid must have the bone
+    pub const fn is_required(self) -> bool {
+        matches!(
+            self,
+            HumanBoneName::Hips
+                | HumanBoneName::Spine
+                | HumanBoneName::Head
+                | HumanBoneName::LeftUpperLeg
+                | HumanBoneName::LeftLowerLeg
+                | HumanBoneName::LeftFoot
+                | HumanBoneName::RightUpperLeg
+                | HumanBoneName::RightLowerLeg
+                | HumanBoneName::RightFoot
+                | HumanBoneName::LeftUpperArm
+                | HumanBoneName::LeftLowerArm
+                | HumanBoneName::LeftHand
+                | HumanBoneName::RightUpperArm
+                | HumanBoneName::RightLowerArm
+                | HumanBoneName::RightHand
+        )
+    }
+
+    /// Returns the part of the body which the bone belongs to
+    pub const fn group(self) -> HumanBoneGroup {
+        match self {
+            HumanBoneName::Hips
+            | HumanBoneName::Spine
+            | HumanBoneName::Chest
+            | HumanBoneName::UpperChest
+            | HumanBoneName::Neck => HumanBoneGroup::Torso,
+            HumanBoneName::Head
+            | HumanBoneName::LeftEye
+            | HumanBoneName::RightEye
+            | HumanBoneName::Jaw => HumanBoneGroup::Head,
+            HumanBoneName::LeftUpperLeg
+            | HumanBoneName::LeftLowerLeg
+            | HumanBoneName::LeftFoot
+            | HumanBoneName::LeftToes => HumanBoneGroup::LeftLeg,
+            HumanBoneName::RightUpperLeg
+            | HumanBoneName::RightLowerLeg
+            | HumanBoneName::RightFoot
+            | HumanBoneName::RightToes => HumanBoneGroup::RightLeg,
+            HumanBoneName::LeftShoulder
+            | HumanBoneName::LeftUpperArm
+            | HumanBoneName::LeftLowerArm
+            | HumanBoneName::LeftHand => HumanBoneGroup::LeftArm,
+            HumanBoneName::RightShoulder
+            | HumanBoneName::RightUpperArm
+            | HumanBoneName::RightLowerArm
+            | HumanBoneName::RightHand => HumanBoneGroup::RightArm,
+            HumanBoneName::LeftThumbMetacarpal
+            | HumanBoneName::LeftThumbProximal
+            | HumanBoneName::LeftThumbDistal
+            | HumanBoneName::LeftIndexProximal
+            | HumanBoneName::LeftIndexIntermediate
+            | HumanBoneName::LeftIndexDistal
+            | HumanBoneName::LeftMiddleProximal
+            | HumanBoneName::LeftMiddleIntermediate
+            | HumanBoneName::LeftMiddleDistal
+            | HumanBoneName::LeftRingProximal
+            | HumanBoneName::LeftRingIntermediate
+            | HumanBoneName::LeftRingDistal
+            | HumanBoneName::LeftLittleProximal
+            | HumanBoneName::LeftLittleIntermediate
+            | HumanBoneName::LeftLittleDistal => HumanBoneGroup::LeftFingers,
+            HumanBoneName::RightThumbMetacarpal
+            | HumanBoneName::RightThumbProximal
+            | HumanBoneName::RightThumbDistal
+            | HumanBoneName::RightIndexProximal
+            | HumanBoneName::RightIndexIntermediate
+            | HumanBoneName::RightIndexDistal
+            | HumanBoneName::RightMiddleProximal
+            | HumanBoneName::RightMiddleIntermediate
+            | HumanBoneName::RightMiddleDistal
+            | HumanBoneName::RightRingProximal
+            | HumanBoneName::RightRingIntermediate
+            | HumanBoneName::RightRingDistal
+            | HumanBoneName::RightLittleProximal
+            | HumanBoneName::RightLittleIntermediate
+            | HumanBoneName::RightLittleDistal => HumanBoneGroup::RightFingers,
+        }
+    }
+
+    /// Returns the side of the body, or `None` for the bones on the center line
+    pub const fn side(self) -> Option<HumanBoneSide> {
+        match self {
+            HumanBoneName::Hips
+            | HumanBoneName::Spine
+            | HumanBoneName::Chest
+            | HumanBoneName::UpperChest
+            | HumanBoneName::Neck
+            | HumanBoneName::Head
+            | HumanBoneName::Jaw => None,
+            HumanBoneName::LeftEye
+            | HumanBoneName::LeftUpperLeg
+            | HumanBoneName::LeftLowerLeg
+            | HumanBoneName::LeftFoot
+            | HumanBoneName::LeftToes
+            | HumanBoneName::LeftShoulder
+            | HumanBoneName::LeftUpperArm
+            | HumanBoneName::LeftLowerArm
+            | HumanBoneName::LeftHand
+            | HumanBoneName::LeftThumbMetacarpal
+            | HumanBoneName::LeftThumbProximal
+            | HumanBoneName::LeftThumbDistal
+            | HumanBoneName::LeftIndexProximal
+            | HumanBoneName::LeftIndexIntermediate
+            | HumanBoneName::LeftIndexDistal
+            | HumanBoneName::LeftMiddleProximal
+            | HumanBoneName::LeftMiddleIntermediate
+            | HumanBoneName::LeftMiddleDistal
+            | HumanBoneName::LeftRingProximal
+            | HumanBoneName::LeftRingIntermediate
+            | HumanBoneName::LeftRingDistal
+            | HumanBoneName::LeftLittleProximal
+            | HumanBoneName::LeftLittleIntermediate
+            | HumanBoneName::LeftLittleDistal => Some(HumanBoneSide::Left),
+            HumanBoneName::RightEye
+            | HumanBoneName::RightUpperLeg
+            | HumanBoneName::RightLowerLeg
+            | HumanBoneName::RightFoot
+            | HumanBoneName::RightToes
+            | HumanBoneName::RightShoulder
+            | HumanBoneName::RightUpperArm
+            | HumanBoneName::RightLowerArm
+            | HumanBoneName::RightHand
+            | HumanBoneName::RightThumbMetacarpal
+            | HumanBoneName::RightThumbProximal
+            | HumanBoneName::RightThumbDistal
+            | HumanBoneName::RightIndexProximal
+            | HumanBoneName::RightIndexIntermediate
+            | HumanBoneName::RightIndexDistal
+            | HumanBoneName::RightMiddleProximal
+            | HumanBoneName::RightMiddleIntermediate
+            | HumanBoneName::RightMiddleDistal
+            | HumanBoneName::RightRingProximal
+            | HumanBoneName::RightRingIntermediate
+            | HumanBoneName::RightRingDistal
+            | HumanBoneName::RightLittleProximal
+            | HumanBoneName::RightLittleIntermediate
+            | HumanBoneName::RightLittleDistal => Some(HumanBoneSide::Right),
+        }
+    }
+
+    /// Returns the bone on the opposite side, e.g. `rightHand` for `leftHand`. The bones on the
+    /// center line are mirrored to themselves.
+    pub const fn mirror(self) -> HumanBoneName {
+        match self {
+            HumanBoneName::LeftEye => HumanBoneName::RightEye,
+            HumanBoneName::RightEye => HumanBoneName::LeftEye,
+            HumanBoneName::LeftUpperLeg => HumanBoneName::RightUpperLeg,
+            HumanBoneName::LeftLowerLeg => HumanBoneName::RightLowerLeg,
+            HumanBoneName::LeftFoot => HumanBoneName::RightFoot,
+            HumanBoneName::LeftToes => HumanBoneName::RightToes,
+            HumanBoneName::RightUpperLeg => HumanBoneName::LeftUpperLeg,
+            HumanBoneName::RightLowerLeg => HumanBoneName::LeftLowerLeg,
+            HumanBoneName::RightFoot => HumanBoneName::LeftFoot,
+            HumanBoneName::RightToes => HumanBoneName::LeftToes,
+            HumanBoneName::LeftShoulder => HumanBoneName::RightShoulder,
+            HumanBoneName::LeftUpperArm => HumanBoneName::RightUpperArm,
+            HumanBoneName::LeftLowerArm => HumanBoneName::RightLowerArm,
+            HumanBoneName::LeftHand => HumanBoneName::RightHand,
+            HumanBoneName::RightShoulder => HumanBoneName::LeftShoulder,
+            HumanBoneName::RightUpperArm => HumanBoneName::LeftUpperArm,
+            HumanBoneName::RightLowerArm => HumanBoneName::LeftLowerArm,
+            HumanBoneName::RightHand => HumanBoneName::LeftHand,
+            HumanBoneName::LeftThumbMetacarpal => HumanBoneName::RightThumbMetacarpal,
+            HumanBoneName::LeftThumbProximal => HumanBoneName::RightThumbProximal,
+            HumanBoneName::LeftThumbDistal => HumanBoneName::RightThumbDistal,
+            HumanBoneName::LeftIndexProximal => HumanBoneName::RightIndexProximal,
+            HumanBoneName::LeftIndexIntermediate => HumanBoneName::RightIndexIntermediate,
+            HumanBoneName::LeftIndexDistal => HumanBoneName::RightIndexDistal,
+            HumanBoneName::LeftMiddleProximal => HumanBoneName::RightMiddleProximal,
+            HumanBoneName::LeftMiddleIntermediate => HumanBoneName::RightMiddleIntermediate,
+            HumanBoneName::LeftMiddleDistal => HumanBoneName::RightMiddleDistal,
+            HumanBoneName::LeftRingProximal => HumanBoneName::RightRingProximal,
+            HumanBoneName::LeftRingIntermediate => HumanBoneName::RightRingIntermediate,
+            HumanBoneName::LeftRingDistal => HumanBoneName::RightRingDistal,
+            HumanBoneName::LeftLittleProximal => HumanBoneName::RightLittleProximal,
+            HumanBoneName::LeftLittleIntermediate => HumanBoneName::RightLittleIntermediate,
+            HumanBoneName::LeftLittleDistal => HumanBoneName::RightLittleDistal,
+            HumanBoneName::RightThumbMetacarpal => HumanBoneName::LeftThumbMetacarpal,
+            HumanBoneName::RightThumbProximal => HumanBoneName::LeftThumbProximal,
+            HumanBoneName::RightThumbDistal => HumanBoneName::LeftThumbDistal,
+            HumanBoneName::RightIndexProximal => HumanBoneName::LeftIndexProximal,
+            HumanBoneName::RightIndexIntermediate => HumanBoneName::LeftIndexIntermediate,
+            HumanBoneName::RightIndexDistal => HumanBoneName::LeftIndexDistal,
+            HumanBoneName::RightMiddleProximal => HumanBoneName::LeftMiddleProximal,
+            HumanBoneName::RightMiddleIntermediate => HumanBoneName::LeftMiddleIntermediate,
+            HumanBoneName::RightMiddleDistal => HumanBoneName::LeftMiddleDistal,
+            HumanBoneName::RightRingProximal => HumanBoneName::LeftRingProximal,
+            HumanBoneName::RightRingIntermediate => HumanBoneName::LeftRingIntermediate,
+            HumanBoneName::RightRingDistal => HumanBoneName::LeftRingDistal,
+            HumanBoneName::RightLittleProximal => HumanBoneName::LeftLittleProximal,
+            HumanBoneName::RightLittleIntermediate => HumanBoneName::LeftLittleIntermediate,
+            HumanBoneName::RightLittleDistal => HumanBoneName::LeftLittleDistal,
+            HumanBoneName::Hips
+            | HumanBoneName::Spine
+            | HumanBoneName::Chest
+            | HumanBoneName::UpperChest
+            | HumanBoneName::Neck
+            | HumanBoneName::Head
+            | HumanBoneName::Jaw => self,
+        }
+    }
+}
+
+/// Part of the body which a human bone belongs to
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum HumanBoneGroup {
+    /// `hips`, `spine`, `chest`, `upperChest` and `neck`
+    Torso,
+    /// `head`, the eyes and `jaw`
+    Head,
+    LeftLeg,
+    RightLeg,
+    /// From the shoulder to the hand
+    LeftArm,
+    /// From the shoulder to the hand
+    RightArm,
+    LeftFingers,
+    RightFingers,
+}
+
+/// Side of the body which a human bone is on
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum HumanBoneSide {
+    Left,
+    Right,
+}
+
+impl From<Bone> for HumanBoneName {
+    /// Converts a bone of VRM 0.x. Note that the thumb bones are shifted: `thumbProximal` and
+    /// `thumbIntermediate` of VRM 0.x are `thumbMetacarpal` and `thumbProximal` of VRM 1.0.
+    fn from(bone: Bone) -> Self {
+        match bone {
+            Bone::Chest => HumanBoneName::Chest,
+            Bone::Head => HumanBoneName::Head,
+            Bone::Hips => HumanBoneName::Hips,
+            Bone::Jaw => HumanBoneName::Jaw,
+            Bone::LeftEye => HumanBoneName::LeftEye,
+            Bone::LeftFoot => HumanBoneName::LeftFoot,
+            Bone::LeftHand => HumanBoneName::LeftHand,
+            Bone::LeftIndexDistal => HumanBoneName::LeftIndexDistal,
+            Bone::LeftIndexIntermediate => HumanBoneName::LeftIndexIntermediate,
+            Bone::LeftIndexProximal => HumanBoneName::LeftIndexProximal,
+            Bone::LeftLittleDistal => HumanBoneName::LeftLittleDistal,
+            Bone::LeftLittleIntermediate => HumanBoneName::LeftLittleIntermediate,
+            Bone::LeftLittleProximal => HumanBoneName::LeftLittleProximal,
+            Bone::LeftLowerArm => HumanBoneName::LeftLowerArm,
+            Bone::LeftLowerLeg => HumanBoneName::LeftLowerLeg,
+            Bone::LeftMiddleDistal => HumanBoneName::LeftMiddleDistal,
+            Bone::LeftMiddleIntermediate => HumanBoneName::LeftMiddleIntermediate,
+            Bone::LeftMiddleProximal => HumanBoneName::LeftMiddleProximal,
+            Bone::LeftRingDistal => HumanBoneName::LeftRingDistal,
+            Bone::LeftRingIntermediate => HumanBoneName::LeftRingIntermediate,
+            Bone::LeftRingProximal => HumanBoneName::LeftRingProximal,
+            Bone::LeftShoulder => HumanBoneName::LeftShoulder,
+            Bone::LeftThumbDistal => HumanBoneName::LeftThumbDistal,
+            Bone::LeftThumbIntermediate => HumanBoneName::LeftThumbProximal,
+            Bone::LeftThumbProximal => HumanBoneName::LeftThumbMetacarpal,
+            Bone::LeftToes => HumanBoneName::LeftToes,
+            Bone::LeftUpperArm => HumanBoneName::LeftUpperArm,
+            Bone::LeftUpperLeg => HumanBoneName::LeftUpperLeg,
+            Bone::Neck => HumanBoneName::Neck,
+            Bone::RightEye => HumanBoneName::RightEye,
+            Bone::RightFoot => HumanBoneName::RightFoot,
+            Bone::RightHand => HumanBoneName::RightHand,
+            Bone::RightIndexDistal => HumanBoneName::RightIndexDistal,
+            Bone::RightIndexIntermediate => HumanBoneName::RightIndexIntermediate,
+            Bone::RightIndexProximal => HumanBoneName::RightIndexProximal,
+            Bone::RightLittleDistal => HumanBoneName::RightLittleDistal,
+            Bone::RightLittleIntermediate => HumanBoneName::RightLittleIntermediate,
+            Bone::RightLittleProximal => HumanBoneName::RightLittleProximal,
+            Bone::RightLowerArm => HumanBoneName::RightLowerArm,
+            Bone::RightLowerLeg => HumanBoneName::RightLowerLeg,
+            Bone::RightMiddleDistal => HumanBoneName::RightMiddleDistal,
+            Bone::RightMiddleIntermediate => HumanBoneName::RightMiddleIntermediate,
+            Bone::RightMiddleProximal => HumanBoneName::RightMiddleProximal,
+            Bone::RightRingDistal => HumanBoneName::RightRingDistal,
+            Bone::RightRingIntermediate => HumanBoneName::RightRingIntermediate,
+            Bone::RightRingProximal => HumanBoneName::RightRingProximal,
+            Bone::RightShoulder => HumanBoneName::RightShoulder,
+            Bone::RightThumbDistal => HumanBoneName::RightThumbDistal,
+            Bone::RightThumbIntermediate => HumanBoneName::RightThumbProximal,
+            Bone::RightThumbProximal => HumanBoneName::RightThumbMetacarpal,
+            Bone::RightToes => HumanBoneName::RightToes,
+            Bone::RightUpperArm => HumanBoneName::RightUpperArm,
+            Bone::RightUpperLeg => HumanBoneName::RightUpperLeg,
+            Bone::Spine => HumanBoneName::Spine,
+            Bone::UpperChest => HumanBoneName::UpperChest,
+        }
+    }
+}
+
+impl From<HumanBoneName> for Bone {
+    /// Converts a bone of VRM 1.0. Note that the thumb bones are shifted: `thumbMetacarpal` and
+    /// `thumbProximal` of VRM 1.0 are `thumbProximal` and `thumbIntermediate` of VRM 0.x.
+    fn from(name: HumanBoneName) -> Self {
+        match name {
+            HumanBoneName::Chest => Bone::Chest,
+            HumanBoneName::Head => Bone::Head,
+            HumanBoneName::Hips => Bone::Hips,
+            HumanBoneName::Jaw => Bone::Jaw,
+            HumanBoneName::LeftEye => Bone::LeftEye,
+            HumanBoneName::LeftFoot => Bone::LeftFoot,
+            HumanBoneName::LeftHand => Bone::LeftHand,
+            HumanBoneName::LeftIndexDistal => Bone::LeftIndexDistal,
+            HumanBoneName::LeftIndexIntermediate => Bone::LeftIndexIntermediate,
+            HumanBoneName::LeftIndexProximal => Bone::LeftIndexProximal,
+            HumanBoneName::LeftLittleDistal => Bone::LeftLittleDistal,
+            HumanBoneName::LeftLittleIntermediate => Bone::LeftLittleIntermediate,
+            HumanBoneName::LeftLittleProximal => Bone::LeftLittleProximal,
+            HumanBoneName::LeftLowerArm => Bone::LeftLowerArm,
+            HumanBoneName::LeftLowerLeg => Bone::LeftLowerLeg,
+            HumanBoneName::LeftMiddleDistal => Bone::LeftMiddleDistal,
+            HumanBoneName::LeftMiddleIntermediate => Bone::LeftMiddleIntermediate,
+            HumanBoneName::LeftMiddleProximal => Bone::LeftMiddleProximal,
+            HumanBoneName::LeftRingDistal => Bone::LeftRingDistal,
+            HumanBoneName::LeftRingIntermediate => Bone::LeftRingIntermediate,
+            HumanBoneName::LeftRingProximal => Bone::LeftRingProximal,
+            HumanBoneName::LeftShoulder => Bone::LeftShoulder,
+            HumanBoneName::LeftThumbDistal => Bone::LeftThumbDistal,
+            HumanBoneName::LeftThumbMetacarpal => Bone::LeftThumbProximal,
+            HumanBoneName::LeftThumbProximal => Bone::LeftThumbIntermediate,
+            HumanBoneName::LeftToes => Bone::LeftToes,
+            HumanBoneName::LeftUpperArm => Bone::LeftUpperArm,
+            HumanBoneName::LeftUpperLeg => Bone::LeftUpperLeg,
+            HumanBoneName::Neck => Bone::Neck,
+            HumanBoneName::RightEye => Bone::RightEye,
+            HumanBoneName::RightFoot => Bone::RightFoot,
+            HumanBoneName::RightHand => Bone::RightHand,
+            HumanBoneName::RightIndexDistal => Bone::RightIndexDistal,
+            HumanBoneName::RightIndexIntermediate => Bone::RightIndexIntermediate,
+            HumanBoneName::RightIndexProximal => Bone::RightIndexProximal,
+            HumanBoneName::RightLittleDistal => Bone::RightLittleDistal,
+            HumanBoneName::RightLittleIntermediate => Bone::RightLittleIntermediate,
+            HumanBoneName::RightLittleProximal => Bone::RightLittleProximal,
+            HumanBoneName::RightLowerArm => Bone::RightLowerArm,
+            HumanBoneName::RightLowerLeg => Bone::RightLowerLeg,
+            HumanBoneName::RightMiddleDistal => Bone::RightMiddleDistal,
+            HumanBoneName::RightMiddleIntermediate => Bone::RightMiddleIntermediate,
+            HumanBoneName::RightMiddleProximal => Bone::RightMiddleProximal,
+            HumanBoneName::RightRingDistal => Bone::RightRingDistal,
+            HumanBoneName::RightRingIntermediate => Bone::RightRingIntermediate,
+            HumanBoneName::RightRingProximal => Bone::RightRingProximal,
+            HumanBoneName::RightShoulder => Bone::RightShoulder,
+            HumanBoneName::RightThumbDistal => Bone::RightThumbDistal,
+            HumanBoneName::RightThumbMetacarpal => Bone::RightThumbProximal,
+            HumanBoneName::RightThumbProximal => Bone::RightThumbIntermediate,
+            HumanBoneName::RightToes => Bone::RightToes,
+            HumanBoneName::RightUpperArm => Bone::RightUpperArm,
+            HumanBoneName::RightUpperLeg => Bone::RightUpperLeg,
+            HumanBoneName::Spine => Bone::Spine,
+            HumanBoneName::UpperChest => Bone::UpperChest,
+        }
+    }
+}
@@ -3,10 +3,12 @@
 pub mod expression_preset_name;
 pub mod human_bone_name;
 
-pub use expression_preset_name::ExpressionPresetName;
-pub use human_bone_name::HumanBoneName;
+pub use expression_preset_name::{ExpressionCategory, ExpressionPresetName};
+pub use human_bone_name::{HumanBoneGroup, HumanBoneName, HumanBoneSide};
 use serde::{Deserialize, Serialize};
 
+use crate::serde_utils::deserialize_map_with_str_keys;
+
 /// VRMC_VRM extension name
 pub const VRMC_VRM: &str = "VRMC_vrm";
 
@@ -42,6 +44,11 @@ pub struct VRMCVrmSchema {
 
     /// Specification version of VRMC_vrm
     pub spec_version: String,
+
+    /// Properties which are not defined in the schema
+    #[cfg(feature = "preserve_unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Definition of expressions
@@ -60,6 +67,11 @@ pub struct Expressions {
     /// Preset expressions
     #[serde(skip_serializing_if = "Option::is_none")]
     pub preset: Option<Preset>,
+
+    /// Properties which are not defined in the schema
+    #[cfg(feature = "preserve_unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Definition of expression by weighted animation
@@ -99,6 +111,11 @@ pub struct Expression {
     /// Texture transform animation references
     #[serde(skip_serializing_if = "Option::is_none")]
     pub texture_transform_binds: Option<Vec<TextureTransformBind>>,
+
+    /// Properties which are not defined in the schema
+    #[cfg(feature = "preserve_unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Material color value associated with a expression
@@ -121,6 +138,11 @@ pub struct MaterialColorBind {
     pub target_value: Vec<f64>,
 
     pub material_color_bind_type: MaterialColorType,
+
+    /// Properties which are not defined in the schema
+    #[cfg(feature = "preserve_unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Morph target value associated with a expression
@@ -143,6 +165,11 @@ pub struct MorphTargetBind {
 
     /// The weight value of target morph target.
     pub weight: f64,
+
+    /// Properties which are not defined in the schema
+    #[cfg(feature = "preserve_unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Texture transform value associated with a expression
@@ -167,10 +194,18 @@ pub struct TextureTransformBind {
     /// uv scaling for TEXCOORD_0
     #[serde(skip_serializing_if = "Option::is_none")]
     pub scale: Option<Vec<f64>>,
+
+    /// Properties which are not defined in the schema
+    #[cfg(feature = "preserve_unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, serde_json::Value>,
 }
 /// Preset expressions
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Preset(pub HashMap<ExpressionPresetName, Expression>);
+pub struct Preset(
+    #[serde(deserialize_with = "deserialize_map_with_str_keys")]
+    pub  HashMap<ExpressionPresetName, Expression>,
+);
 
 /// First-person perspective settings
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -185,6 +220,11 @@ pub struct FirstPerson {
     /// Mesh rendering annotation for cameras.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub mesh_annotations: Option<Vec<MeshAnnotation>>,
+
+    /// Properties which are not defined in the schema
+    #[cfg(feature = "preserve_unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Specify how the mesh should be interpreted by the camera
@@ -207,6 +247,11 @@ pub struct MeshAnnotation {
     /// How the camera interprets the mesh.
     #[serde(rename = "type")]
     pub mesh_annotation_type: FirstPersonType,
+
+    /// Properties which are not defined in the schema
+    #[cfg(feature = "preserve_unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Correspondence between nodes and human bones
@@ -220,12 +265,20 @@ pub struct Humanoid {
     pub extras: Option<serde_json::Value>,
 
     pub human_bones: HumanBones,
+
+    /// Properties which are not defined in the schema
+    #[cfg(feature = "preserve_unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Represents a set of humanBones of a humanoid.
-// FIXME: all bones are optional by now
+// NOTE: all bones are optional here. `validate::validate_humanoid` checks the required bones
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct HumanBones(pub HashMap<HumanBoneName, Option<HumanBone>>);
+pub struct HumanBones(
+    #[serde(deserialize_with = "deserialize_map_with_str_keys")]
+    pub  HashMap<HumanBoneName, Option<HumanBone>>,
+);
 
 /// Represents a single bone of a Humanoid.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -243,6 +296,11 @@ pub struct HumanBone {
     #[cfg(not(feature = "gltf_index"))]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub node: Option<usize>,
+
+    /// Properties which are not defined in the schema
+    #[cfg(feature = "preserve_unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Eye gaze control
@@ -278,6 +336,11 @@ pub struct LookAt {
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub look_at_type: Option<LookAtType>,
+
+    /// Properties which are not defined in the schema
+    #[cfg(feature = "preserve_unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, serde_json::Value>,
 }
 
 /// LookAt range definition
@@ -298,6 +361,11 @@ pub struct LookAtRangeMap {
     /// Degree for type.bone, Weight for type.expressions
     #[serde(skip_serializing_if = "Option::is_none")]
     pub output_scale: Option<f64>,
+
+    /// Properties which are not defined in the schema
+    #[cfg(feature = "preserve_unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Meta information of the VRM model
@@ -387,6 +455,11 @@ pub struct Meta {
     /// The version of the model
     #[serde(skip_serializing_if = "Option::is_none")]
     pub version: Option<String>,
+
+    /// Properties which are not defined in the schema
+    #[cfg(feature = "preserve_unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Debug, Clone, Copy, Hash, Serialize, Deserialize)]
@@ -0,0 +1,168 @@
+//! Data structures for the [`VRMC_vrm_animation`](https://github.com/vrm-c/vrm-specification/tree/master/specification/VRMC_vrm_animation-1.0) 1.0 glTF Extension.
+//!
+//! This extension is used in VRM Animation (`.vrma`) files. It maps glTF nodes of the animation to
+//! human bones, expressions and the lookAt target of a VRM model.
+
+use serde::{Deserialize, Serialize};
+
+use crate::serde_utils::deserialize_map_with_str_keys;
+
+use crate::vrmc_vrm_1_0::{ExpressionPresetName, HumanBoneName};
+
+/// VRMC_vrm_animation extension name
+pub const VRMC_VRM_ANIMATION: &str = "VRMC_vrm_animation";
+
+#[cfg(feature = "rustc_hash")]
+use rustc_hash::FxHashMap as HashMap;
+#[cfg(not(feature = "rustc_hash"))]
+use std::collections::HashMap;
+
+/// VRM Animation maps the nodes of an animation to the humanoid bones and expressions of VRM
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VRMCVrmAnimationSchema {
+    /// Expressions of the animation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expressions: Option<Expressions>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extensions: Option<HashMap<String, HashMap<String, Option<serde_json::Value>>>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extras: Option<serde_json::Value>,
+
+    /// Correspondence between nodes and human bones
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub humanoid: Option<Humanoid>,
+
+    /// Eye gaze control
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub look_at: Option<LookAt>,
+
+    /// Specification version of VRMC_vrm_animation
+    pub spec_version: String,
+
+    /// Properties which are not defined in the schema
+    #[cfg(feature = "preserve_unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Correspondence between nodes and human bones
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Humanoid {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extensions: Option<HashMap<String, HashMap<String, Option<serde_json::Value>>>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extras: Option<serde_json::Value>,
+
+    pub human_bones: HumanBones,
+
+    /// Properties which are not defined in the schema
+    #[cfg(feature = "preserve_unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Represents a set of humanBones of a humanoid.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HumanBones(
+    #[serde(deserialize_with = "deserialize_map_with_str_keys")]
+    pub  HashMap<HumanBoneName, HumanBone>,
+);
+
+/// Represents a single bone of a Humanoid.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HumanBone {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extensions: Option<HashMap<String, HashMap<String, Option<serde_json::Value>>>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extras: Option<serde_json::Value>,
+
+    /// Represents a single glTF node tied to this humanBone.
+    #[cfg(feature = "gltf_index")]
+    pub node: gltf::json::Index<gltf::json::Node>,
+    #[cfg(not(feature = "gltf_index"))]
+    pub node: usize,
+
+    /// Properties which are not defined in the schema
+    #[cfg(feature = "preserve_unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Definition of expressions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Expressions {
+    /// Custom expressions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom: Option<HashMap<String, Expression>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extensions: Option<HashMap<String, HashMap<String, Option<serde_json::Value>>>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extras: Option<serde_json::Value>,
+
+    /// Preset expressions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preset: Option<Preset>,
+
+    /// Properties which are not defined in the schema
+    #[cfg(feature = "preserve_unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Preset expressions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Preset(
+    #[serde(deserialize_with = "deserialize_map_with_str_keys")]
+    pub  HashMap<ExpressionPresetName, Expression>,
+);
+
+/// An expression of the animation. The X component of the translation of the node represents the
+/// weight of the expression.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Expression {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extensions: Option<HashMap<String, HashMap<String, Option<serde_json::Value>>>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extras: Option<serde_json::Value>,
+
+    /// The index of the node that represents the expression.
+    #[cfg(feature = "gltf_index")]
+    pub node: gltf::json::Index<gltf::json::Node>,
+    #[cfg(not(feature = "gltf_index"))]
+    pub node: usize,
+
+    /// Properties which are not defined in the schema
+    #[cfg(feature = "preserve_unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Eye gaze control. The world position of the node is the target of the gaze.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LookAt {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extensions: Option<HashMap<String, HashMap<String, Option<serde_json::Value>>>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extras: Option<serde_json::Value>,
+
+    /// The index of the node that represents the lookAt target.
+    #[cfg(feature = "gltf_index")]
+    pub node: gltf::json::Index<gltf::json::Node>,
+    #[cfg(not(feature = "gltf_index"))]
+    pub node: usize,
+
+    /// Properties which are not defined in the schema
+    #[cfg(feature = "preserve_unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, serde_json::Value>,
+}
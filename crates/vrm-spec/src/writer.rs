@@ -0,0 +1,279 @@
+//! Writer of VRM files which re-embeds edited VRM extensions into an existing GLB.
+//!
+//! Only the JSON chunk is rewritten. The BIN chunk is copied as is, so meshes and textures are
+//! not re-encoded.
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! use vrm_spec::document::{VrmDocument, VrmExtensions};
+//!
+//! let glb = std::fs::read("model.vrm").expect("ok");
+//! let mut vrm = VrmDocument::from_slice(&glb).expect("ok");
+//! if let VrmExtensions::Vrm1(vrm1) = &mut vrm.extensions {
+//!     vrm1.vrmc_vrm.meta.name = "renamed".to_owned();
+//! }
+//! let output = vrm_spec::writer::write_glb(&glb, &vrm.extensions).expect("ok");
+//! std::fs::write("renamed.vrm", output).expect("ok");
+//! ```
+
+use std::fmt;
+
+use serde::Serialize;
+
+use crate::{
+    document::VrmExtensions, vrm_0_0::VRM,
+    vrmc_materials_hdr_emissive_multiplier_1_0::VRMC_MATERIALS_HDR_EMISSIVE_MULTIPLIER,
+    vrmc_materials_mtoon_1_0::VRMC_MATERIALS_MTOON, vrmc_node_constraint_1_0::VRMC_NODE_CONSTRAINT,
+    vrmc_spring_bone_1_0::VRMC_SPRING_BONE,
+    vrmc_spring_bone_extended_collider_1_0::VRMC_SPRING_BONE_EXTENDED_COLLIDER,
+    vrmc_vrm_1_0::VRMC_VRM,
+};
+
+/// Error while writing a GLB
+#[derive(Debug)]
+pub enum WriteError {
+    /// Failed to load the input GLB or to encode the output GLB
+    Gltf(gltf::Error),
+
+    /// Failed to serialize a VRM data structure
+    Serialize(serde_json::Error),
+
+    /// The number of per-object extensions doesn't match the number of the glTF objects
+    CountMismatch {
+        /// JSON pointer to the array of the glTF objects, e.g. `/materials`
+        pointer: String,
+        expected: usize,
+        actual: usize,
+    },
+}
+
+impl fmt::Display for WriteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WriteError::Gltf(e) => write!(f, "failed to write glTF: {}", e),
+            WriteError::Serialize(e) => write!(f, "failed to serialize: {}", e),
+            WriteError::CountMismatch {
+                pointer,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "'{}' has {} objects but {} were given",
+                pointer, expected, actual
+            ),
+        }
+    }
+}
+
+impl std::error::Error for WriteError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            WriteError::Gltf(e) => Some(e),
+            WriteError::Serialize(e) => Some(e),
+            WriteError::CountMismatch { .. } => None,
+        }
+    }
+}
+
+impl From<gltf::Error> for WriteError {
+    fn from(e: gltf::Error) -> Self {
+        WriteError::Gltf(e)
+    }
+}
+
+/// Writes `extensions` into the JSON chunk of `glb` and returns the new GLB.
+///
+/// For VRM 1.0, `VRMC_vrm` is replaced, and `VRMC_springBone`, `VRMC_materials_mtoon` and
+/// `VRMC_node_constraint` are replaced or removed according to `extensions`, and
+/// `VRMC_springBone_extended_collider` is declared if any collider has it. For VRM 0.x, `VRM`
+/// is replaced. The extensions of the other version are removed, e.g. `VRM` when writing VRM 1.0
+/// into a GLB of VRM 0.x, and every VRM 1.0 extension including
+/// `VRMC_springBone_extended_collider` and `VRMC_materials_hdr_emissiveMultiplier` when writing
+/// VRM 0.x, so that the output declares only one version. `extensionsUsed` is
+/// updated accordingly. Any other part of the JSON is kept as is.
+pub fn write_glb(glb: &[u8], extensions: &VrmExtensions) -> Result<Vec<u8>, WriteError> {
+    let mut glb = gltf::binary::Glb::from_slice(glb)?;
+    let mut root: serde_json::Value = serde_json::from_slice(&glb.json)
+        .map_err(|e| WriteError::Gltf(gltf::Error::Deserialize(e)))?;
+    let root = root.as_object_mut().ok_or_else(|| {
+        WriteError::Gltf(gltf::Error::Deserialize(serde::de::Error::custom(
+            "glTF JSON must be an object",
+        )))
+    })?;
+
+    match extensions {
+        VrmExtensions::Vrm0(vrm) => {
+            set_extension(root, VRM, Some(to_value(vrm)?));
+            set_extension_used(root, VRM, true);
+
+            for name in [VRMC_VRM, VRMC_SPRING_BONE] {
+                set_extension(root, name, None);
+                set_extension_used(root, name, false);
+            }
+            // lives on the colliders of `VRMC_springBone`, which is removed above
+            set_extension_used(root, VRMC_SPRING_BONE_EXTENDED_COLLIDER, false);
+            remove_object_extensions(root, "materials", VRMC_MATERIALS_MTOON);
+            remove_object_extensions(root, "materials", VRMC_MATERIALS_HDR_EMISSIVE_MULTIPLIER);
+            remove_object_extensions(root, "nodes", VRMC_NODE_CONSTRAINT);
+        }
+        VrmExtensions::Vrm1(vrm1) => {
+            set_extension(root, VRM, None);
+            set_extension_used(root, VRM, false);
+
+            set_extension(root, VRMC_VRM, Some(to_value(&vrm1.vrmc_vrm)?));
+            set_extension_used(root, VRMC_VRM, true);
+
+            let spring_bone = vrm1.vrmc_spring_bone.as_ref().map(to_value).transpose()?;
+            set_extension_used(root, VRMC_SPRING_BONE, spring_bone.is_some());
+            set_extension(root, VRMC_SPRING_BONE, spring_bone);
+            let extended_collider = vrm1
+                .vrmc_spring_bone
+                .iter()
+                .flat_map(|spring_bone| spring_bone.colliders.iter().flatten())
+                .filter_map(|collider| collider.extensions.as_ref())
+                .any(|extensions| extensions.contains_key(VRMC_SPRING_BONE_EXTENDED_COLLIDER));
+            set_extension_used(root, VRMC_SPRING_BONE_EXTENDED_COLLIDER, extended_collider);
+
+            set_object_extensions(
+                root,
+                "materials",
+                VRMC_MATERIALS_MTOON,
+                &vrm1.vrmc_materials_mtoon,
+            )?;
+            set_object_extensions(
+                root,
+                "nodes",
+                VRMC_NODE_CONSTRAINT,
+                &vrm1.vrmc_node_constraints,
+            )?;
+        }
+    }
+
+    let json = serde_json::to_vec(root).map_err(WriteError::Serialize)?;
+    glb.json = json.into();
+    Ok(glb.to_vec()?)
+}
+
+fn to_value<T>(value: &T) -> Result<serde_json::Value, WriteError>
+where
+    T: Serialize,
+{
+    serde_json::to_value(value).map_err(WriteError::Serialize)
+}
+
+/// Sets the extension of a glTF object, or removes it if `value` is `None`
+fn set_extension(
+    object: &mut serde_json::Map<String, serde_json::Value>,
+    name: &str,
+    value: Option<serde_json::Value>,
+) {
+    match value {
+        Some(value) => {
+            let extensions = object
+                .entry("extensions")
+                .or_insert_with(|| serde_json::Value::Object(Default::default()));
+            if !extensions.is_object() {
+                *extensions = serde_json::Value::Object(Default::default());
+            }
+            if let Some(extensions) = extensions.as_object_mut() {
+                extensions.insert(name.to_owned(), value);
+            }
+        }
+        None => {
+            if let Some(extensions) = object
+                .get_mut("extensions")
+                .and_then(serde_json::Value::as_object_mut)
+            {
+                extensions.remove(name);
+                if extensions.is_empty() {
+                    object.remove("extensions");
+                }
+            }
+        }
+    }
+}
+
+/// Sets the extension of each object in the array of `key`, e.g. `materials`
+fn set_object_extensions<T>(
+    root: &mut serde_json::Map<String, serde_json::Value>,
+    key: &'static str,
+    name: &str,
+    values: &[Option<T>],
+) -> Result<(), WriteError>
+where
+    T: Serialize,
+{
+    let objects = root
+        .get_mut(key)
+        .and_then(serde_json::Value::as_array_mut)
+        .map(|objects| &mut objects[..])
+        .unwrap_or_default();
+    if objects.len() != values.len() {
+        return Err(WriteError::CountMismatch {
+            pointer: format!("/{}", key),
+            expected: objects.len(),
+            actual: values.len(),
+        });
+    }
+
+    let mut used = false;
+    for (object, value) in objects.iter_mut().zip(values) {
+        if let Some(object) = object.as_object_mut() {
+            let value = value.as_ref().map(to_value).transpose()?;
+            used |= value.is_some();
+            set_extension(object, name, value);
+        }
+    }
+    set_extension_used(root, name, used);
+
+    Ok(())
+}
+
+/// Removes the extension of each object in the array of `key`, e.g. `materials`
+fn remove_object_extensions(
+    root: &mut serde_json::Map<String, serde_json::Value>,
+    key: &'static str,
+    name: &str,
+) {
+    if let Some(objects) = root.get_mut(key).and_then(serde_json::Value::as_array_mut) {
+        for object in objects
+            .iter_mut()
+            .filter_map(serde_json::Value::as_object_mut)
+        {
+            set_extension(object, name, None);
+        }
+    }
+    set_extension_used(root, name, false);
+}
+
+/// Adds the extension name to `extensionsUsed`, or removes it from `extensionsUsed` and
+/// `extensionsRequired` if the extension is no longer used
+fn set_extension_used(
+    root: &mut serde_json::Map<String, serde_json::Value>,
+    name: &str,
+    used: bool,
+) {
+    if used {
+        let extensions_used = root
+            .entry("extensionsUsed")
+            .or_insert_with(|| serde_json::Value::Array(Vec::new()));
+        if !extensions_used.is_array() {
+            *extensions_used = serde_json::Value::Array(Vec::new());
+        }
+        if let Some(extensions_used) = extensions_used.as_array_mut() {
+            if !extensions_used.iter().any(|used| used == name) {
+                extensions_used.push(name.into());
+            }
+        }
+    } else {
+        for key in ["extensionsUsed", "extensionsRequired"] {
+            if let Some(names) = root.get_mut(key).and_then(serde_json::Value::as_array_mut) {
+                names.retain(|used| used != name);
+                if names.is_empty() {
+                    root.remove(key);
+                }
+            }
+        }
+    }
+}
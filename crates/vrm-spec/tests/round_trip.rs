@@ -0,0 +1,170 @@
+#![cfg(feature = "preserve_unknown_fields")]
+
+use serde::{de::DeserializeOwned, Serialize};
+use vrm_spec::{vrm_0_0, vrmc_node_constraint_1_0, vrmc_spring_bone_1_0, vrmc_vrm_1_0};
+
+/// Indices where Unity writes `-1`, which this crate drops as documented in the crate docs. `*`
+/// matches any token.
+const MINUS_ONE_INDICES: &[&str] = &[
+    "/blendShapeMaster/blendShapeGroups/*/binds/*/mesh",
+    "/firstPerson/firstPersonBone",
+    "/firstPerson/meshAnnotations/*/mesh",
+    "/humanoid/humanBones/*/node",
+    "/materialProperties/*/textureProperties/*",
+    "/meta/texture",
+    "/secondaryAnimation/boneGroups/*/center",
+    "/secondaryAnimation/colliderGroups/*/node",
+];
+
+/// Properties of VRM 0.x which this crate drops when they are `null`, as documented in the crate
+/// docs
+const NULL_PROPERTIES: &[&str] = &["/materialProperties/*/floatProperties/*"];
+
+/// Normalizes what this crate is documented not to preserve: the representation of numbers and
+/// the properties of [`MINUS_ONE_INDICES`] and [`NULL_PROPERTIES`].
+/// `test_round_trip_vrm0_dropped_values` checks the loss itself
+fn normalize(value: serde_json::Value, pointer: &str) -> serde_json::Value {
+    match value {
+        serde_json::Value::Number(n) => serde_json::json!(n.as_f64().expect("finite")),
+        serde_json::Value::Array(values) => values
+            .into_iter()
+            .enumerate()
+            .map(|(i, v)| normalize(v, &format!("{}/{}", pointer, i)))
+            .collect(),
+        serde_json::Value::Object(map) => map
+            .into_iter()
+            .filter_map(|(k, v)| {
+                let pointer = format!("{}/{}", pointer, k);
+                let listed =
+                    |patterns: &[&str]| patterns.iter().any(|pattern| matches(pattern, &pointer));
+                let dropped = (v.as_i64() == Some(-1) && listed(MINUS_ONE_INDICES))
+                    || (v.is_null() && listed(NULL_PROPERTIES));
+                (!dropped).then(|| {
+                    let v = normalize(v, &pointer);
+                    (k, v)
+                })
+            })
+            .collect(),
+        value => value,
+    }
+}
+
+fn matches(pattern: &str, pointer: &str) -> bool {
+    let pattern: Vec<_> = pattern.split('/').collect();
+    let pointer: Vec<_> = pointer.split('/').collect();
+    pattern.len() == pointer.len()
+        && pattern
+            .iter()
+            .zip(&pointer)
+            .all(|(pattern, token)| *pattern == "*" || pattern == token)
+}
+
+fn assert_round_trip<T>(value: &serde_json::Value)
+where
+    T: Serialize + DeserializeOwned,
+{
+    let schema: T = serde_json::from_value(value.to_owned()).expect("ok");
+    let output = serde_json::to_value(schema).expect("ok");
+    assert_eq!(normalize(output, ""), normalize(value.to_owned(), ""));
+}
+
+#[test]
+fn test_round_trip_vrm0() {
+    let file = include_bytes!("../../../fixtures/AvatarSample_A.vrm");
+    let (doc, _, _) = gltf::import_slice(file).expect("ok");
+    let value = doc.extension_value(vrm_0_0::VRM).expect("exist");
+
+    assert_round_trip::<vrm_0_0::VRM0Schema>(value);
+}
+
+#[test]
+fn test_round_trip_vrm0_dropped_values() {
+    let value = serde_json::json!({
+        "exporterVersion": "UniVRM-0.99.0",
+        "meta": { "title": "test", "texture": -1 },
+        "humanoid": {
+            "humanBones": [
+                { "bone": "hips", "node": 0, "useDefaultValues": true },
+                { "bone": "chest", "node": -1, "useDefaultValues": true }
+            ]
+        },
+        "materialProperties": [
+            {
+                "name": "body",
+                "floatProperties": { "_Cutoff": 0.5, "_BumpScale": null },
+                "textureProperties": { "_MainTex": 0, "_BumpMap": -1 }
+            }
+        ]
+    });
+    let vrm: vrm_0_0::VRM0Schema = serde_json::from_value(value.to_owned()).expect("ok");
+    let output = serde_json::to_value(vrm).expect("ok");
+
+    // `-1` indices and `null` float properties are dropped
+    assert_eq!(output["meta"], serde_json::json!({ "title": "test" }));
+    assert_eq!(
+        output["humanoid"]["humanBones"][1],
+        serde_json::json!({ "bone": "chest", "useDefaultValues": true })
+    );
+    let material = &output["materialProperties"][0];
+    assert_eq!(
+        material["floatProperties"],
+        serde_json::json!({ "_Cutoff": 0.5 })
+    );
+    assert_eq!(
+        material["textureProperties"],
+        serde_json::json!({ "_MainTex": 0 })
+    );
+
+    // and nothing else
+    assert_eq!(normalize(output, ""), normalize(value, ""));
+
+    // the values are kept anywhere else
+    assert_eq!(
+        normalize(
+            serde_json::json!({ "meta": { "texture": -1, "version": null, "title": -1 } }),
+            ""
+        ),
+        serde_json::json!({ "meta": { "version": null, "title": -1.0 } })
+    );
+}
+
+#[test]
+fn test_round_trip_vrm1() {
+    let file = include_bytes!("../../../fixtures/VRM1_Constraint_Twist_Sample.vrm");
+    let (doc, _, _) = gltf::import_slice(file).expect("ok");
+    let value = doc.extension_value(vrmc_vrm_1_0::VRMC_VRM).expect("exist");
+    assert_round_trip::<vrmc_vrm_1_0::VRMCVrmSchema>(value);
+
+    let value = doc
+        .extension_value(vrmc_spring_bone_1_0::VRMC_SPRING_BONE)
+        .expect("exist");
+    assert_round_trip::<vrmc_spring_bone_1_0::VrmcSpringBoneSchema>(value);
+
+    for node in doc.nodes() {
+        if let Some(value) = node.extension_value(vrmc_node_constraint_1_0::VRMC_NODE_CONSTRAINT) {
+            assert_round_trip::<vrmc_node_constraint_1_0::VrmcNodeConstraintSchema>(value);
+        }
+    }
+}
+
+#[test]
+fn test_unknown_fields() {
+    let value = serde_json::json!({
+        "specVersion": "1.0",
+        "meta": {
+            "name": "test",
+            "authors": ["pixiv"],
+            "licenseUrl": "https://vrm.dev/licenses/1.0/",
+            "vendorField": { "foo": [1, 2, 3] }
+        },
+        "humanoid": {
+            "humanBones": { "hips": { "node": 0, "futureProperty": true } }
+        },
+        "futureProperty": "bar"
+    });
+    let vrm: vrmc_vrm_1_0::VRMCVrmSchema = serde_json::from_value(value.to_owned()).expect("ok");
+    assert_eq!(vrm.unknown_fields["futureProperty"], "bar");
+    assert_eq!(vrm.meta.unknown_fields["vendorField"]["foo"][2], 3);
+
+    assert_eq!(serde_json::to_value(vrm).expect("ok"), value);
+}
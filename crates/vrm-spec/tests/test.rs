@@ -1,6 +1,22 @@
-use vrm_spec::{vrm_0_0, vrmc_spring_bone_1_0, vrmc_vrm_1_0};
+use vrm_spec::{
+    coordinate,
+    document::{VrmDocument, VrmExtensions},
+    migrate, vrm_0_0, vrmc_materials_hdr_emissive_multiplier_1_0, vrmc_node_constraint_1_0,
+    vrmc_spring_bone_1_0, vrmc_vrm_1_0, vrmc_vrm_animation_1_0, writer,
+};
+
+/// Serializes `value` into pretty JSON with sorted keys, so that the snapshot depends neither on
+/// the iteration order of `HashMap` nor on `preserve_unknown_fields`
+fn sorted_json<T: serde::Serialize>(value: &T) -> String {
+    let value = serde_json::to_value(value).expect("ok");
+    serde_json::to_string_pretty(&value).expect("ok")
+}
 
 #[test]
+#[cfg_attr(
+    feature = "preserve_unknown_fields",
+    ignore = "the Debug output gains `unknown_fields` with preserve_unknown_fields"
+)]
 fn test_vrm0() {
     let file = include_bytes!("../../../fixtures/AvatarSample_A.vrm");
     let (doc, _, _) = gltf::import_slice(file).expect("ok");
@@ -11,6 +27,10 @@ fn test_vrm0() {
 }
 
 #[test]
+#[cfg_attr(
+    feature = "preserve_unknown_fields",
+    ignore = "the Debug output gains `unknown_fields` with preserve_unknown_fields"
+)]
 fn test_vrm1() {
     let file = include_bytes!("../../../fixtures/VRM1_Constraint_Twist_Sample.vrm");
     let (doc, _, _) = gltf::import_slice(file).expect("ok");
@@ -29,3 +49,2140 @@ fn test_vrm1() {
 
     insta::assert_debug_snapshot!(vrmc_spring_bone);
 }
+
+#[test]
+fn test_vrm1_node_constraint() {
+    let doc = gltf::Gltf::from_slice(
+        br#"{
+            "asset": { "version": "2.0" },
+            "extensionsUsed": ["VRMC_node_constraint"],
+            "nodes": [
+                { "children": [1, 2, 3] },
+                {
+                    "extensions": {
+                        "VRMC_node_constraint": {
+                            "specVersion": "1.0",
+                            "constraint": { "roll": { "source": 0, "rollAxis": "Y", "weight": 0.5 } }
+                        }
+                    }
+                },
+                {
+                    "extensions": {
+                        "VRMC_node_constraint": {
+                            "specVersion": "1.0",
+                            "constraint": { "aim": { "source": 1, "aimAxis": "PositiveZ" } }
+                        }
+                    }
+                },
+                {
+                    "extensions": {
+                        "VRMC_node_constraint": {
+                            "specVersion": "1.0",
+                            "constraint": { "rotation": { "source": 2, "weight": 1.0 } }
+                        }
+                    }
+                }
+            ]
+        }"#,
+    )
+    .expect("ok");
+    let constraints: Vec<(usize, vrmc_node_constraint_1_0::VrmcNodeConstraintSchema)> = doc
+        .nodes()
+        .filter_map(|node| {
+            let value = node.extension_value(vrmc_node_constraint_1_0::VRMC_NODE_CONSTRAINT)?;
+            let constraint = serde_json::from_value(value.to_owned()).expect("ok");
+            Some((node.index(), constraint))
+        })
+        .collect();
+
+    assert_eq!(constraints.len(), 3);
+    insta::assert_snapshot!(sorted_json(&constraints));
+}
+
+#[test]
+#[cfg_attr(
+    feature = "preserve_unknown_fields",
+    ignore = "the Debug output gains `unknown_fields` with preserve_unknown_fields"
+)]
+fn test_vrma() {
+    let file = include_bytes!("../../../fixtures/VRMA_Sample.vrma");
+    let (doc, _, _) = gltf::import_slice(file).expect("ok");
+    let value = doc
+        .extension_value(vrmc_vrm_animation_1_0::VRMC_VRM_ANIMATION)
+        .expect("exist");
+    let vrmc_vrm_animation: vrmc_vrm_animation_1_0::VRMCVrmAnimationSchema =
+        serde_json::from_value(value.to_owned()).expect("ok");
+
+    insta::assert_debug_snapshot!(vrmc_vrm_animation);
+}
+
+#[test]
+fn test_spring_bone_extended_collider() {
+    let colliders: Vec<vrmc_spring_bone_1_0::Collider> = serde_json::from_str(
+        r#"[
+            {
+                "node": 0,
+                "shape": { "sphere": { "offset": [0.0, 0.1, 0.0], "radius": 0.5 } },
+                "extensions": {
+                    "VRMC_springBone_extended_collider": {
+                        "specVersion": "1.0",
+                        "shape": {
+                            "sphere": { "offset": [0.0, 0.1, 0.0], "radius": 0.5, "inside": true }
+                        }
+                    }
+                }
+            },
+            {
+                "node": 1,
+                "shape": { "capsule": { "offset": [0.0, 0.0, 0.0], "radius": 0.1, "tail": [0.0, 1.0, 0.0] } }
+            }
+        ]"#,
+    )
+    .expect("ok");
+
+    match colliders[0].effective_shape().expect("ok") {
+        vrmc_spring_bone_1_0::EffectiveColliderShape::Extended(shape) => {
+            let sphere = shape.sphere.expect("exist");
+            assert_eq!(sphere.inside, Some(true));
+            assert_eq!(sphere.radius, Some(0.5));
+        }
+        shape => panic!("unexpected shape: {:?}", shape),
+    }
+
+    assert!(colliders[1].extended_collider().is_none());
+    match colliders[1].effective_shape().expect("ok") {
+        vrmc_spring_bone_1_0::EffectiveColliderShape::Standard(shape) => {
+            assert_eq!(shape.capsule.expect("exist").tail, Some([0.0, 1.0, 0.0]));
+        }
+        shape => panic!("unexpected shape: {:?}", shape),
+    }
+}
+
+#[test]
+fn test_collider_shape_kind() {
+    use vrmc_spring_bone_1_0::{ColliderShape, ColliderShapeKind};
+
+    let shapes: Vec<ColliderShape> = serde_json::from_str(
+        r#"[
+            { "sphere": { "radius": 0.5 } },
+            { "capsule": { "offset": [0.0, 0.1, 0.0], "tail": [0.0, 1.0, 0.0] } },
+            {},
+            { "sphere": {}, "capsule": {} }
+        ]"#,
+    )
+    .expect("ok");
+
+    assert_eq!(
+        shapes[0].kind().expect("ok"),
+        ColliderShapeKind::Sphere {
+            offset: [0.0, 0.0, 0.0],
+            radius: 0.5
+        }
+    );
+    let capsule = shapes[1].kind().expect("ok");
+    assert_eq!(
+        capsule,
+        ColliderShapeKind::Capsule {
+            offset: [0.0, 0.1, 0.0],
+            radius: 0.0,
+            tail: [0.0, 1.0, 0.0]
+        }
+    );
+    assert_eq!(
+        shapes[2].kind().expect_err("malformed").to_string(),
+        "invalid collider shape: neither sphere nor capsule is given"
+    );
+    assert_eq!(
+        shapes[3].kind().expect_err("malformed").to_string(),
+        "invalid collider shape: both sphere and capsule are given"
+    );
+
+    // the wire format is kept
+    let shape = ColliderShape::from(capsule);
+    assert_eq!(
+        serde_json::to_value(&shape).expect("ok"),
+        serde_json::json!({
+            "capsule": { "offset": [0.0, 0.1, 0.0], "radius": 0.0, "tail": [0.0, 1.0, 0.0] }
+        })
+    );
+    assert_eq!(shape.kind().expect("ok"), capsule);
+}
+
+#[test]
+fn test_effective_emissive_factor() {
+    use vrmc_materials_hdr_emissive_multiplier_1_0::effective_emissive_factor;
+
+    let material: gltf::json::Material = serde_json::from_str(
+        r#"{
+            "emissiveFactor": [1.0, 0.5, 0.0],
+            "extensions": {
+                "VRMC_materials_hdr_emissiveMultiplier": {
+                    "specVersion": "1.0",
+                    "emissiveMultiplier": 4.0
+                }
+            }
+        }"#,
+    )
+    .expect("ok");
+    assert_eq!(
+        effective_emissive_factor(&material).expect("ok"),
+        [4.0, 2.0, 0.0]
+    );
+
+    let material: gltf::json::Material = serde_json::from_str(
+        r#"{
+            "emissiveFactor": [1.0, 0.5, 0.0],
+            "extensions": {
+                "KHR_materials_emissive_strength": { "emissiveStrength": 2.0 },
+                "VRMC_materials_hdr_emissiveMultiplier": {
+                    "specVersion": "1.0",
+                    "emissiveMultiplier": 2.0
+                }
+            }
+        }"#,
+    )
+    .expect("ok");
+    assert_eq!(
+        effective_emissive_factor(&material).expect("ok"),
+        [4.0, 2.0, 0.0]
+    );
+
+    let material: gltf::json::Material = serde_json::from_str(
+        r#"{
+            "emissiveFactor": [1.0, 0.5, 0.0],
+            "extensions": {
+                "KHR_materials_emissive_strength": { "emissiveStrength": 3.0 }
+            }
+        }"#,
+    )
+    .expect("ok");
+    assert_eq!(
+        effective_emissive_factor(&material).expect("ok"),
+        [3.0, 1.5, 0.0]
+    );
+
+    let material: gltf::json::Material = serde_json::from_str(
+        r#"{
+            "extensions": {
+                "KHR_materials_emissive_strength": { "emissiveStrength": "bright" }
+            }
+        }"#,
+    )
+    .expect("ok");
+    assert!(effective_emissive_factor(&material).is_err());
+
+    let material: gltf::json::Material =
+        serde_json::from_str(r#"{ "emissiveFactor": [1.0, 0.5, 0.0] }"#).expect("ok");
+    assert_eq!(
+        effective_emissive_factor(&material).expect("ok"),
+        [1.0, 0.5, 0.0]
+    );
+}
+
+#[test]
+fn test_vrm_document() {
+    let file = include_bytes!("../../../fixtures/AvatarSample_A.vrm");
+    let vrm = VrmDocument::from_slice(file).expect("ok");
+    assert!(matches!(vrm.extensions, VrmExtensions::Vrm0(_)));
+
+    let file = include_bytes!("../../../fixtures/VRM1_Constraint_Twist_Sample.vrm");
+    let vrm = VrmDocument::from_slice(file).expect("ok");
+    let VrmExtensions::Vrm1(vrm1) = vrm.extensions else {
+        panic!("expected VRM 1.0");
+    };
+    assert!(vrm1.vrmc_spring_bone.is_some());
+    assert_eq!(
+        vrm1.vrmc_materials_mtoon.len(),
+        vrm.gltf.document.materials().len()
+    );
+    assert!(vrm1.vrmc_node_constraints.iter().any(Option::is_some));
+
+    let file = include_bytes!("../../../fixtures/VRMA_Sample.vrma");
+    assert!(matches!(
+        VrmDocument::from_slice(file),
+        Err(vrm_spec::Error::MissingExtension(_))
+    ));
+}
+
+#[test]
+fn test_error_pointer() {
+    let value = serde_json::json!({
+        "specVersion": "1.0",
+        "meta": { "name": "test", "authors": ["pixiv"], "licenseUrl": "https://vrm.dev/licenses/1.0/" },
+        "humanoid": {
+            "humanBones": {
+                "hips": { "node": 0 },
+                "leftHand": { "node": "broken" }
+            }
+        }
+    });
+    let err = vrm_spec::from_value::<vrmc_vrm_1_0::VRMCVrmSchema>(&value).expect_err("broken");
+    match err {
+        vrm_spec::Error::Deserialize { pointer, .. } => {
+            assert_eq!(pointer, "/humanoid/humanBones/leftHand/node")
+        }
+        err => panic!("unexpected error: {}", err),
+    }
+}
+
+#[test]
+fn test_write_glb() {
+    let file = include_bytes!("../../../fixtures/VRM1_Constraint_Twist_Sample.vrm");
+    let mut vrm = VrmDocument::from_slice(file).expect("ok");
+    let VrmExtensions::Vrm1(vrm1) = &mut vrm.extensions else {
+        panic!("expected VRM 1.0");
+    };
+    vrm1.vrmc_vrm.meta.name = "Edited".to_owned();
+    vrm1.vrmc_spring_bone = None;
+
+    let output = writer::write_glb(file, &vrm.extensions).expect("ok");
+    assert_eq!(output.len() % 4, 0);
+
+    let original = gltf::binary::Glb::from_slice(file).expect("ok");
+    let written = gltf::binary::Glb::from_slice(&output).expect("ok");
+    assert_eq!(written.header.length as usize, output.len());
+    assert_eq!(written.json.len() % 4, 0);
+    assert_eq!(written.bin, original.bin);
+
+    let written = VrmDocument::from_slice(&output).expect("ok");
+    let VrmExtensions::Vrm1(vrm1) = written.extensions else {
+        panic!("expected VRM 1.0");
+    };
+    assert_eq!(vrm1.vrmc_vrm.meta.name, "Edited");
+    assert!(vrm1.vrmc_spring_bone.is_none());
+    assert!(!written
+        .gltf
+        .document
+        .extensions_used()
+        .any(|name| name == vrmc_spring_bone_1_0::VRMC_SPRING_BONE));
+}
+
+#[test]
+fn test_write_glb_other_version() {
+    let json = serde_json::json!({
+        "asset": { "version": "2.0" },
+        "extensionsUsed": [
+            "VRM",
+            "KHR_materials_unlit",
+            "VRMC_materials_mtoon",
+            "VRMC_materials_hdr_emissiveMultiplier",
+            "VRMC_springBone_extended_collider"
+        ],
+        "extensions": {
+            "VRM": {
+                "meta": { "title": "sample" },
+                "humanoid": { "humanBones": [{ "bone": "hips", "node": 0 }] }
+            }
+        },
+        "materials": [{
+            "extensions": {
+                "VRMC_materials_mtoon": { "specVersion": "1.0" },
+                "VRMC_materials_hdr_emissiveMultiplier": { "emissiveMultiplier": 2.0 }
+            }
+        }],
+        "nodes": [{ "name": "hips" }]
+    });
+    let glb = gltf::binary::Glb {
+        header: gltf::binary::Header {
+            magic: *b"glTF",
+            version: 2,
+            length: 0,
+        },
+        json: serde_json::to_vec(&json).expect("ok").into(),
+        bin: None,
+    }
+    .to_vec()
+    .expect("ok");
+
+    let vrm = VrmDocument::from_slice(&glb).expect("ok");
+    let VrmExtensions::Vrm0(vrm0) = &vrm.extensions else {
+        panic!("expected VRM 0.x");
+    };
+    let (vrmc_vrm, _) = migrate::migrate_vrm0(vrm0, &vrm.gltf.document);
+    let vrm1 = VrmExtensions::Vrm1(Box::new(vrm_spec::document::Vrm1Extensions {
+        vrmc_vrm,
+        vrmc_spring_bone: None,
+        vrmc_materials_mtoon: vec![None],
+        vrmc_node_constraints: vec![None],
+    }));
+
+    // the stale extensions of VRM 1.0 are removed
+    let output = writer::write_glb(&glb, &vrm.extensions).expect("ok");
+    let written = VrmDocument::from_slice(&output).expect("ok");
+    let extensions_used: Vec<_> = written.gltf.document.extensions_used().collect();
+    assert_eq!(extensions_used, ["VRM", "KHR_materials_unlit"]);
+    let material = &written.gltf.document.as_json().materials[0];
+    assert!(material.extensions.is_none());
+
+    // VRM 0.x to VRM 1.0
+    let output = writer::write_glb(&glb, &vrm1).expect("ok");
+    let written = VrmDocument::from_slice(&output).expect("ok");
+    assert!(matches!(written.extensions, VrmExtensions::Vrm1(_)));
+    let extensions_used: Vec<_> = written.gltf.document.extensions_used().collect();
+    // the HDR emissive multiplier is kept on the material, the extended collider is not used
+    assert_eq!(
+        extensions_used,
+        [
+            "KHR_materials_unlit",
+            "VRMC_materials_hdr_emissiveMultiplier",
+            vrmc_vrm_1_0::VRMC_VRM
+        ]
+    );
+    assert!(written
+        .gltf
+        .document
+        .extension_value(vrm_0_0::VRM)
+        .is_none());
+
+    // and back to VRM 0.x
+    let output = writer::write_glb(&output, &vrm.extensions).expect("ok");
+    let written = VrmDocument::from_slice(&output).expect("ok");
+    assert!(matches!(written.extensions, VrmExtensions::Vrm0(_)));
+    let extensions_used: Vec<_> = written.gltf.document.extensions_used().collect();
+    assert_eq!(extensions_used, ["KHR_materials_unlit", vrm_0_0::VRM]);
+    assert!(written
+        .gltf
+        .document
+        .extension_value(vrmc_vrm_1_0::VRMC_VRM)
+        .is_none());
+}
+
+#[test]
+fn test_migrate_vrm0() {
+    let file = include_bytes!("../../../fixtures/AvatarSample_A.vrm");
+    let vrm = VrmDocument::from_slice(file).expect("ok");
+    let VrmExtensions::Vrm0(vrm0) = &vrm.extensions else {
+        panic!("expected VRM 0.x");
+    };
+    let (vrmc_vrm, _) = migrate::migrate_vrm0(vrm0, &vrm.gltf.document);
+
+    let human_bones = &vrmc_vrm.humanoid.human_bones.0;
+    for name in [
+        vrmc_vrm_1_0::HumanBoneName::Hips,
+        vrmc_vrm_1_0::HumanBoneName::Head,
+        vrmc_vrm_1_0::HumanBoneName::LeftThumbMetacarpal,
+    ] {
+        assert!(human_bones.contains_key(&name), "{:?}", name);
+    }
+    assert!(!vrmc_vrm.meta.name.is_empty());
+    assert_eq!(vrmc_vrm.meta.license_url, "https://vrm.dev/licenses/1.0/");
+
+    let preset = vrmc_vrm.expressions.and_then(|e| e.preset).expect("exist");
+    for expression in preset.0.values() {
+        for bind in expression.morph_target_binds.iter().flatten() {
+            assert!((0.0..=1.0).contains(&bind.weight));
+        }
+    }
+    assert!(preset
+        .0
+        .contains_key(&vrmc_vrm_1_0::ExpressionPresetName::Aa));
+}
+
+#[test]
+fn test_migrate_vrm0_fields() {
+    let gltf = gltf::Gltf::from_slice(
+        br#"{
+            "asset": { "version": "2.0" },
+            "accessors": [
+                {
+                    "bufferView": 0, "componentType": 5126, "count": 1, "type": "VEC3",
+                    "min": [0, 0, 0], "max": [0, 0, 0]
+                }
+            ],
+            "bufferViews": [{ "buffer": 0, "byteLength": 12 }],
+            "buffers": [{ "byteLength": 12 }],
+            "images": [{ "uri": "thumbnail.png" }],
+            "materials": [{ "name": "Face" }],
+            "meshes": [{ "primitives": [{ "attributes": { "POSITION": 0 } }] }],
+            "nodes": [
+                { "children": [1, 2] },
+                { "mesh": 0 },
+                { "mesh": 0 },
+                {}
+            ],
+            "textures": [{ "source": 0 }]
+        }"#,
+    )
+    .expect("ok");
+    let vrm0: vrm_0_0::VRM0Schema = serde_json::from_value(serde_json::json!({
+        "humanoid": {
+            "humanBones": [
+                { "bone": "hips", "node": 0, "useDefaultValues": true },
+                { "bone": "head", "node": 3, "useDefaultValues": true },
+                { "bone": "leftThumbProximal", "node": 1, "useDefaultValues": true },
+                { "bone": "leftThumbIntermediate", "node": 2, "useDefaultValues": true },
+                { "bone": "spine", "useDefaultValues": true }
+            ]
+        },
+        "meta": {
+            "title": "Sample",
+            "author": "pixiv",
+            "texture": 0,
+            "licenseName": "CC_BY_NC",
+            "commercialUssageName": "Allow",
+            "allowedUserName": "ExplicitlyLicensedPerson",
+            "violentUssageName": "Disallow",
+            "sexualUssageName": "Allow"
+        },
+        "blendShapeMaster": {
+            "blendShapeGroups": [
+                {
+                    "name": "A",
+                    "presetName": "a",
+                    "binds": [{ "mesh": 0, "index": 3, "weight": 50 }]
+                },
+                {
+                    "name": "Joy",
+                    "presetName": "joy",
+                    "materialValues": [
+                        { "materialName": "Face", "propertyName": "_MainTex_ST", "targetValue": [0.5, 0.5, 0.25, 0.25] },
+                        { "materialName": "Face", "propertyName": "_Color", "targetValue": [1, 0, 0, 1] },
+                        { "materialName": "Face", "propertyName": "_Cutoff", "targetValue": [0.5, 0, 0, 0] }
+                    ]
+                },
+                { "name": "Smile", "presetName": "unknown", "isBinary": true }
+            ]
+        },
+        "firstPerson": {
+            "firstPersonBone": 3,
+            "firstPersonBoneOffset": { "x": 0.1, "y": 0.06, "z": 0 },
+            "meshAnnotations": [{ "mesh": 0, "firstPersonFlag": "ThirdPersonOnly" }],
+            "lookAtTypeName": "BlendShape",
+            "lookAtHorizontalOuter": { "curve": [0, 0, 0, 1, 1, 1, 1, 0], "xRange": 90, "yRange": 1 }
+        }
+    }))
+    .expect("ok");
+
+    let (vrmc_vrm, report) = migrate::migrate_vrm0(&vrm0, &gltf.document);
+    assert_eq!(vrmc_vrm.spec_version, "1.0");
+
+    let human_bones = &vrmc_vrm.humanoid.human_bones.0;
+    let node = |name| {
+        human_bones[&name]
+            .as_ref()
+            .and_then(|bone| bone.node)
+            .map(|node| node.value())
+    };
+    assert_eq!(
+        node(vrmc_vrm_1_0::HumanBoneName::LeftThumbMetacarpal),
+        Some(1)
+    );
+    assert_eq!(
+        node(vrmc_vrm_1_0::HumanBoneName::LeftThumbProximal),
+        Some(2)
+    );
+    assert!(!human_bones.contains_key(&vrmc_vrm_1_0::HumanBoneName::Spine));
+
+    let meta = &vrmc_vrm.meta;
+    assert_eq!(meta.name, "Sample");
+    assert_eq!(meta.authors, ["pixiv"]);
+    assert_eq!(meta.thumbnail_image.map(|image| image.value()), Some(0));
+    assert_eq!(
+        meta.other_license_url.as_deref(),
+        Some("https://creativecommons.org/licenses/by-nc/4.0/")
+    );
+    assert!(matches!(
+        meta.commercial_usage,
+        Some(vrmc_vrm_1_0::CommercialUsageType::PersonalNonProfit)
+    ));
+    assert!(matches!(
+        meta.avatar_permission,
+        Some(vrmc_vrm_1_0::AvatarPermissionType::OnlySeparatelyLicensedPerson)
+    ));
+    assert_eq!(meta.allow_excessively_violent_usage, Some(false));
+    assert_eq!(meta.allow_excessively_sexual_usage, Some(true));
+
+    let expressions = vrmc_vrm.expressions.expect("exist");
+    let preset = expressions.preset.expect("exist").0;
+    let aa = &preset[&vrmc_vrm_1_0::ExpressionPresetName::Aa];
+    let binds = aa.morph_target_binds.as_ref().expect("exist");
+    assert_eq!(
+        binds
+            .iter()
+            .map(|bind| (bind.node.value(), bind.index, bind.weight))
+            .collect::<Vec<_>>(),
+        [(1, 3, 0.5), (2, 3, 0.5)]
+    );
+    let happy = &preset[&vrmc_vrm_1_0::ExpressionPresetName::Happy];
+    let transform = &happy.texture_transform_binds.as_ref().expect("exist")[0];
+    assert_eq!(transform.scale.as_deref(), Some(&[0.5, 0.5][..]));
+    assert_eq!(transform.offset.as_deref(), Some(&[0.25, 0.25][..]));
+    let color = &happy.material_color_binds.as_ref().expect("exist")[0];
+    assert_eq!(color.target_value, [1.0, 0.0, 0.0, 1.0]);
+    let custom = expressions.custom.expect("exist");
+    assert_eq!(custom["Smile"].is_binary, Some(true));
+
+    let annotations = vrmc_vrm
+        .first_person
+        .and_then(|first_person| first_person.mesh_annotations)
+        .expect("exist");
+    assert_eq!(annotations.len(), 2);
+    let look_at = vrmc_vrm.look_at.expect("exist");
+    assert_eq!(look_at.offset_from_head_bone, Some(vec![-0.1, 0.06, 0.0]));
+    assert!(matches!(
+        look_at.look_at_type,
+        Some(vrmc_vrm_1_0::LookAtType::Expression)
+    ));
+    assert_eq!(
+        look_at
+            .range_map_horizontal_outer
+            .and_then(|map| map.input_max_value),
+        Some(90.0)
+    );
+
+    let pointers: Vec<&str> = report
+        .notes
+        .iter()
+        .map(|note| note.pointer.as_str())
+        .collect();
+    assert!(pointers.contains(&"/humanoid/humanBones/4"));
+    assert!(pointers.contains(&"/meta/commercialUssageName"));
+    assert!(pointers.contains(&"/blendShapeMaster/blendShapeGroups/1/materialValues/2"));
+    assert!(!pointers.contains(&"/firstPerson/firstPersonBone"));
+}
+
+#[test]
+fn test_migrate_spring_bone() {
+    let file = include_bytes!("../../../fixtures/AvatarSample_A.vrm");
+    let vrm = VrmDocument::from_slice(file).expect("ok");
+    let VrmExtensions::Vrm0(vrm0) = &vrm.extensions else {
+        panic!("expected VRM 0.x");
+    };
+    let secondary_animation = vrm0.secondary_animation.as_ref().expect("exist");
+    let mut report = migrate::MigrationReport::default();
+    let vrmc_spring_bone =
+        migrate::migrate_spring_bone(secondary_animation, &vrm.gltf.document, &mut report);
+
+    let collider_groups = vrmc_spring_bone.collider_groups.expect("exist");
+    assert_eq!(
+        collider_groups.len(),
+        secondary_animation
+            .collider_groups
+            .as_ref()
+            .expect("exist")
+            .len()
+    );
+    let colliders = vrmc_spring_bone.colliders.expect("exist");
+    assert!(collider_groups
+        .iter()
+        .flat_map(|group| &group.colliders)
+        .all(|&collider| collider < colliders.len()));
+
+    let springs = vrmc_spring_bone.springs.expect("exist");
+    let mut joints: Vec<usize> = springs
+        .iter()
+        .flat_map(|spring| &spring.joints)
+        .map(|joint| joint.node.value())
+        .collect();
+    let count = joints.len();
+    joints.sort_unstable();
+    joints.dedup();
+    assert_eq!(joints.len(), count);
+
+    let nodes: Vec<_> = vrm.gltf.document.nodes().collect();
+    for spring in &springs {
+        for pair in spring.joints.windows(2) {
+            assert!(nodes[pair[0].node.value()]
+                .children()
+                .any(|child| child.index() == pair[1].node.value()));
+        }
+    }
+}
+
+#[test]
+fn test_migrate_spring_bone_chains() {
+    let gltf = gltf::Gltf::from_slice(
+        br#"{
+            "asset": { "version": "2.0" },
+            "nodes": [
+                { "children": [1] },
+                { "children": [2, 4] },
+                { "children": [3] },
+                {},
+                { "children": [5] },
+                {},
+                {}
+            ]
+        }"#,
+    )
+    .expect("ok");
+    let secondary_animation: vrm_0_0::VRMSecondaryAnimation =
+        serde_json::from_value(serde_json::json!({
+            "boneGroups": [
+                {
+                    "comment": "hair",
+                    "stiffiness": 1.5,
+                    "dragForce": 0.4,
+                    "gravityPower": 0.2,
+                    "gravityDir": { "x": 1, "y": -1, "z": 0 },
+                    "hitRadius": 0.02,
+                    "center": -1,
+                    "bones": [1],
+                    "colliderGroups": [0, 3]
+                },
+                { "bones": [4], "colliderGroups": [] }
+            ],
+            "colliderGroups": [
+                {
+                    "node": 6,
+                    "colliders": [{ "offset": { "x": 0.1, "y": 0.2, "z": 0.3 }, "radius": 0.05 }]
+                }
+            ]
+        }))
+        .expect("ok");
+    let mut report = migrate::MigrationReport::default();
+    let vrmc_spring_bone =
+        migrate::migrate_spring_bone(&secondary_animation, &gltf.document, &mut report);
+
+    let colliders = vrmc_spring_bone.colliders.expect("exist");
+    assert_eq!(colliders[0].node.value(), 6);
+    let sphere = colliders[0].shape.sphere.as_ref().expect("exist");
+    assert_eq!(sphere.offset, Some([-0.1, 0.2, 0.3]));
+    assert_eq!(sphere.radius, Some(0.05));
+    assert_eq!(
+        vrmc_spring_bone.collider_groups.expect("exist")[0].colliders,
+        [0]
+    );
+
+    let springs = vrmc_spring_bone.springs.expect("exist");
+    let chains: Vec<Vec<usize>> = springs
+        .iter()
+        .map(|spring| {
+            spring
+                .joints
+                .iter()
+                .map(|joint| joint.node.value())
+                .collect()
+        })
+        .collect();
+    assert_eq!(chains, [vec![1, 2, 3], vec![4, 5]]);
+    assert_eq!(springs[0].name.as_deref(), Some("hair"));
+    assert!(springs[0].center.is_none());
+    // the collider group which doesn't exist is dropped
+    assert_eq!(springs[0].collider_groups.as_deref(), Some(&[0][..]));
+    assert_eq!(springs[1].collider_groups.as_deref(), Some(&[0][..]));
+
+    let joint = &springs[0].joints[1];
+    assert_eq!(joint.stiffness, Some(1.5));
+    assert_eq!(joint.drag_force, Some(0.4));
+    assert_eq!(joint.gravity_power, Some(0.2));
+    assert_eq!(joint.gravity_dir, Some([-1.0, -1.0, 0.0]));
+    assert_eq!(joint.hit_radius, Some(0.02));
+
+    assert!(report
+        .notes
+        .iter()
+        .any(|note| note.pointer == "/secondaryAnimation/boneGroups/1/bones/0"));
+    assert!(report
+        .notes
+        .iter()
+        .any(|note| note.pointer == "/secondaryAnimation/boneGroups/0/colliderGroups/1"));
+}
+
+#[test]
+fn test_migrate_materials() {
+    let file = include_bytes!("../../../fixtures/AvatarSample_A.vrm");
+    let vrm = VrmDocument::from_slice(file).expect("ok");
+    let VrmExtensions::Vrm0(vrm0) = &vrm.extensions else {
+        panic!("expected VRM 0.x");
+    };
+    let material_properties = vrm0.material_properties.as_deref().expect("exist");
+    let mut report = migrate::MigrationReport::default();
+    let materials = migrate::migrate_materials(material_properties, &mut report);
+
+    assert_eq!(materials.len(), vrm.gltf.document.materials().len());
+    assert!(materials.iter().any(Option::is_some));
+    for migrated in materials.iter().flatten() {
+        let toony = migrated.mtoon.shading_toony_factor.expect("exist");
+        let shift = migrated.mtoon.shading_shift_factor.expect("exist");
+        assert!((0.0..=1.0).contains(&toony));
+        assert!((-1.0..=1.0).contains(&shift));
+    }
+}
+
+#[test]
+fn test_migrate_mtoon_render_queue() {
+    let material = |blend_mode: i64, render_queue: Option<i64>| {
+        let mut material = serde_json::json!({
+            "shader": "VRM/MToon",
+            "floatProperties": { "_BlendMode": blend_mode }
+        });
+        if let Some(render_queue) = render_queue {
+            material["renderQueue"] = render_queue.into();
+        }
+        material
+    };
+    let material_properties: Vec<vrm_0_0::VRMMaterial> =
+        serde_json::from_value(serde_json::json!([
+            material(2, None),
+            material(2, Some(-1)),
+            material(2, Some(2995)),
+            material(3, None),
+            material(3, Some(-1)),
+            material(3, Some(2503)),
+        ]))
+        .expect("ok");
+    let mut report = migrate::MigrationReport::default();
+    let offsets: Vec<_> = migrate::migrate_materials(&material_properties, &mut report)
+        .iter()
+        .map(|migrated| {
+            migrated
+                .as_ref()
+                .expect("exist")
+                .mtoon
+                .render_queue_offset_number
+        })
+        .collect();
+    assert_eq!(
+        offsets,
+        [Some(0), Some(0), Some(-5), Some(0), Some(0), Some(2)]
+    );
+}
+
+#[test]
+fn test_migrate_mtoon() {
+    let material_properties: Vec<vrm_0_0::VRMMaterial> =
+        serde_json::from_value(serde_json::json!([
+            {
+                "name": "Hair",
+                "shader": "VRM/MToon",
+                "renderQueue": 2505,
+                "floatProperties": {
+                    "_BlendMode": 3,
+                    "_CullMode": 0,
+                    "_ShadeToony": 0.5,
+                    "_ShadeShift": -0.5,
+                    "_BumpScale": 0.5,
+                    "_IndirectLightIntensity": 0.25,
+                    "_OutlineWidthMode": 1,
+                    "_OutlineWidth": 0.5,
+                    "_OutlineColorMode": 1,
+                    "_OutlineLightingMix": 0.75,
+                    "_UvAnimScrollY": 0.5,
+                    "_ReceiveShadowRate": 0.5
+                },
+                "vectorProperties": {
+                    "_Color": [1, 0.5, 0, 0.8],
+                    "_ShadeColor": [0.5, 0.5, 0.5, 1],
+                    "_EmissionColor": [0.5, 0.25, 0, 1],
+                    "_MainTex_ST": [2, 0.5, 0.25, 0.125]
+                },
+                "textureProperties": {
+                    "_MainTex": 0,
+                    "_BumpMap": 1,
+                    "_SphereAdd": 2
+                },
+                "keywordMap": {},
+                "tagMap": {}
+            },
+            { "name": "Skin", "shader": "VRM_USE_GLTFSHADER", "floatProperties": {} },
+            { "name": "Eye", "shader": "VRM/UnlitTexture", "floatProperties": {} }
+        ]))
+        .expect("ok");
+    let mut report = migrate::MigrationReport::default();
+    let materials = migrate::migrate_materials(&material_properties, &mut report);
+    assert_eq!(materials.len(), 3);
+    assert!(materials[1].is_none());
+    assert!(materials[2].is_none());
+
+    let migrated = materials[0].as_ref().expect("exist");
+    let material = &migrated.material;
+    assert_eq!(material.name.as_deref(), Some("Hair"));
+    assert_eq!(
+        material.alpha_mode,
+        gltf::json::validation::Checked::Valid(gltf::json::material::AlphaMode::Blend)
+    );
+    assert!(material.double_sided);
+    let [r, g, b, a] = material.pbr_metallic_roughness.base_color_factor.0;
+    assert_eq!((r, b, a), (1.0, 0.0, 0.8));
+    assert!((g - 0.214).abs() < 1e-3);
+    assert_eq!(
+        material
+            .pbr_metallic_roughness
+            .base_color_texture
+            .as_ref()
+            .map(|info| info.index.value()),
+        Some(0)
+    );
+    let normal = material.normal_texture.as_ref().expect("exist");
+    assert_eq!((normal.index.value(), normal.scale), (1, 0.5));
+    assert_eq!(material.emissive_factor.0, [0.5, 0.25, 0.0]);
+
+    // the origin of UV is flipped from bottom-left to top-left
+    let texture_transform = serde_json::json!({ "offset": [0.25, 0.375], "scale": [2.0, 0.5] });
+    let base_color_texture = material.pbr_metallic_roughness.base_color_texture.as_ref();
+    let extensions = base_color_texture.and_then(|info| info.extensions.as_ref());
+    assert_eq!(
+        extensions.and_then(|extensions| extensions.others.get("KHR_texture_transform")),
+        Some(&texture_transform)
+    );
+    let extensions = normal.extensions.as_ref();
+    assert_eq!(
+        extensions.and_then(|extensions| extensions.others.get("KHR_texture_transform")),
+        Some(&texture_transform)
+    );
+
+    let mtoon = &migrated.mtoon;
+    assert_eq!(mtoon.transparent_with_z_write, Some(true));
+    assert_eq!(mtoon.render_queue_offset_number, Some(4));
+    assert_eq!(mtoon.shading_toony_factor, Some(0.625));
+    assert_eq!(mtoon.shading_shift_factor, Some(0.125));
+    assert_eq!(mtoon.gi_equalization_factor, Some(0.75));
+    assert_eq!(
+        mtoon.matcap_texture.as_ref().map(|info| info.index.value()),
+        Some(2)
+    );
+    assert_eq!(mtoon.matcap_factor, Some([1.0, 1.0, 1.0]));
+    // the matcap texture is sampled in view space
+    assert!(mtoon
+        .matcap_texture
+        .as_ref()
+        .map_or(false, |info| info.extensions.is_none()));
+    assert!(matches!(
+        mtoon.outline_width_mode,
+        Some(vrm_spec::vrmc_materials_mtoon_1_0::OutlineWidthMode::WorldCoordinates)
+    ));
+    assert_eq!(mtoon.outline_width_factor, Some(0.005));
+    assert_eq!(mtoon.outline_lighting_mix_factor, Some(0.75));
+    assert_eq!(mtoon.uv_animation_scroll_y_speed_factor, Some(-0.5));
+
+    let pointers: Vec<&str> = report
+        .notes
+        .iter()
+        .map(|note| note.pointer.as_str())
+        .collect();
+    assert_eq!(
+        pointers,
+        [
+            "/materialProperties/0/floatProperties/_ReceiveShadowRate",
+            "/materialProperties/2/shader"
+        ]
+    );
+}
+
+#[test]
+fn test_downgrade_vrm1() {
+    use vrm_spec::downgrade::{self, UnsupportedFeature};
+
+    let gltf = gltf::Gltf::from_slice(
+        br#"{
+            "asset": { "version": "2.0" },
+            "accessors": [
+                {
+                    "bufferView": 0, "componentType": 5126, "count": 1, "type": "VEC3",
+                    "min": [0, 0, 0], "max": [0, 0, 0]
+                }
+            ],
+            "bufferViews": [{ "buffer": 0, "byteLength": 12 }],
+            "buffers": [{ "byteLength": 12 }],
+            "materials": [{ "name": "Face" }, { "name": "Skin" }],
+            "meshes": [{ "primitives": [{ "attributes": { "POSITION": 0 } }] }],
+            "nodes": [
+                { "children": [1] },
+                { "children": [2], "mesh": 0 },
+                { "children": [3] },
+                {}
+            ]
+        }"#,
+    )
+    .expect("ok");
+    let vrm1 = vrm_spec::document::Vrm1Extensions {
+        vrmc_vrm: serde_json::from_value(serde_json::json!({
+            "specVersion": "1.0",
+            "meta": {
+                "name": "Sample",
+                "authors": ["pixiv", "VRM Consortium"],
+                "licenseUrl": "https://vrm.dev/licenses/1.0/",
+                "avatarPermission": "everyone",
+                "commercialUsage": "personalProfit"
+            },
+            "humanoid": {
+                "humanBones": {
+                    "hips": { "node": 0 },
+                    "head": { "node": 1 },
+                    "leftThumbMetacarpal": { "node": 2 }
+                }
+            },
+            "expressions": {
+                "preset": {
+                    "happy": {
+                        "morphTargetBinds": [{ "node": 1, "index": 2, "weight": 0.5 }],
+                        "textureTransformBinds": [{ "material": 0, "scale": [2, 2] }],
+                        "overrideBlink": "block"
+                    },
+                    "surprised": {}
+                },
+                "custom": {
+                    "Smile": { "isBinary": true }
+                }
+            },
+            "lookAt": { "offsetFromHeadBone": [0.1, 0.06, 0] }
+        }))
+        .expect("ok"),
+        vrmc_spring_bone: Some(
+            serde_json::from_value(serde_json::json!({
+                "specVersion": "1.0",
+                "colliders": [
+                    { "node": 0, "shape": { "sphere": { "offset": [0.1, 0, 0], "radius": 0.1 } } },
+                    {
+                        "node": 1,
+                        "shape": { "capsule": { "offset": [0, 0, 0], "radius": 0.05, "tail": [0, 0.1, 0] } }
+                    }
+                ],
+                "colliderGroups": [{ "colliders": [0, 1] }],
+                "springs": [
+                    {
+                        "name": "Hair",
+                        "joints": [
+                            { "node": 2, "stiffness": 0.5, "gravityDir": [1, 0, 0] },
+                            { "node": 3 }
+                        ],
+                        "colliderGroups": [0]
+                    }
+                ]
+            }))
+            .expect("ok"),
+        ),
+        vrmc_materials_mtoon: vec![None, None],
+        vrmc_node_constraints: vec![
+            None,
+            None,
+            Some(
+                serde_json::from_value(serde_json::json!({
+                    "specVersion": "1.0",
+                    "constraint": { "roll": { "source": 1, "rollAxis": "Y" } }
+                }))
+                .expect("ok"),
+            ),
+            None,
+        ],
+    };
+
+    let (vrm0, report) = downgrade::downgrade_vrm1(&vrm1, &gltf.document);
+    assert_eq!(vrm0.spec_version.as_deref(), Some("0.0"));
+
+    let human_bones = vrm0
+        .humanoid
+        .and_then(|humanoid| humanoid.human_bones)
+        .expect("exist");
+    let bones: Vec<_> = human_bones
+        .iter()
+        .map(|bone| (bone.bone, bone.node.map(|node| node.value())))
+        .collect();
+    assert!(matches!(
+        bones[..],
+        [
+            (Some(vrm_0_0::Bone::Hips), Some(0)),
+            (Some(vrm_0_0::Bone::Head), Some(1)),
+            (Some(vrm_0_0::Bone::LeftThumbProximal), Some(2))
+        ]
+    ));
+
+    let meta = vrm0.meta.expect("exist");
+    assert_eq!(meta.author.as_deref(), Some("pixiv, VRM Consortium"));
+    assert!(matches!(
+        meta.license_name,
+        Some(vrm_0_0::LicenseName::Other)
+    ));
+    assert_eq!(
+        meta.other_license_url.as_deref(),
+        Some("https://vrm.dev/licenses/1.0/")
+    );
+
+    let groups = vrm0
+        .blend_shape_master
+        .and_then(|master| master.blend_shape_groups)
+        .expect("exist");
+    let joy = groups
+        .iter()
+        .find(|group| matches!(group.preset_name, Some(vrm_0_0::PresetName::Joy)))
+        .expect("exist");
+    let bind = &joy.binds.as_ref().expect("exist")[0];
+    assert_eq!(bind.mesh.map(|mesh| mesh.value()), Some(0));
+    assert_eq!(bind.index, Some(2));
+    assert_eq!(bind.weight, Some(50.0));
+    assert!(groups
+        .iter()
+        .any(|group| group.name.as_deref() == Some("Smile") && group.is_binary == Some(true)));
+
+    let first_person = vrm0.first_person.expect("exist");
+    let offset = first_person.first_person_bone_offset.expect("exist");
+    assert_eq!((offset.x, offset.y), (Some(-0.1), Some(0.06)));
+
+    let secondary_animation = vrm0.secondary_animation.expect("exist");
+    let collider_groups = secondary_animation.collider_groups.expect("exist");
+    assert_eq!(collider_groups.len(), 2);
+    let bone_group = &secondary_animation.bone_groups.expect("exist")[0];
+    assert_eq!(
+        bone_group
+            .bones
+            .iter()
+            .flatten()
+            .map(|bone| bone.value())
+            .collect::<Vec<_>>(),
+        [2]
+    );
+    assert_eq!(bone_group.collider_groups.as_deref(), Some(&[0, 1][..]));
+    assert_eq!(bone_group.stiffiness, Some(0.5));
+    let gravity_dir = bone_group.gravity_dir.as_ref().expect("exist");
+    assert_eq!(gravity_dir.x, Some(-1.0));
+
+    let material_properties = vrm0.material_properties.expect("exist");
+    assert_eq!(material_properties.len(), 2);
+    assert_eq!(
+        material_properties[0].shader.as_deref(),
+        Some("VRM_USE_GLTFSHADER")
+    );
+
+    let mut features: Vec<_> = report
+        .unsupported
+        .iter()
+        .map(|unsupported| unsupported.feature)
+        .collect();
+    features.dedup();
+    for feature in [
+        UnsupportedFeature::CapsuleCollider,
+        UnsupportedFeature::TextureTransformBind,
+        UnsupportedFeature::ExpressionOverride,
+        UnsupportedFeature::ExpressionPreset,
+        UnsupportedFeature::MetaPermission,
+        UnsupportedFeature::NodeConstraint,
+    ] {
+        assert!(features.contains(&feature), "{:?}", feature);
+    }
+    assert!(report
+        .unsupported
+        .iter()
+        .any(|unsupported| unsupported.pointer
+            == "/extensions/VRMC_springBone/colliders/1/shape/capsule"));
+    assert!(report
+        .unsupported
+        .iter()
+        .any(|unsupported| unsupported.pointer == "/nodes/2/extensions/VRMC_node_constraint"));
+}
+
+#[test]
+fn test_downgrade_invalid_collider_shape() {
+    use vrm_spec::downgrade::{self, UnsupportedFeature};
+
+    let gltf = gltf::Gltf::from_slice(br#"{ "asset": { "version": "2.0" }, "nodes": [{}, {}] }"#)
+        .expect("ok");
+    let spring_bone: vrmc_spring_bone_1_0::VrmcSpringBoneSchema =
+        serde_json::from_value(serde_json::json!({
+            "specVersion": "1.0",
+            "colliders": [
+                {
+                    "node": 0,
+                    "shape": {
+                        "sphere": { "offset": [0.1, 0, 0], "radius": 0.1 },
+                        "capsule": { "radius": 0.05, "tail": [0, 0.1, 0] }
+                    }
+                },
+                { "node": 1, "shape": {} }
+            ],
+            "colliderGroups": [{ "colliders": [0, 1] }]
+        }))
+        .expect("ok");
+    let mut report = downgrade::DowngradeReport::default();
+    let secondary_animation =
+        downgrade::downgrade_spring_bone(&spring_bone, &gltf.document, &mut report);
+
+    // the sphere is used if both are given, and the collider without a shape is dropped
+    let collider_groups = secondary_animation.collider_groups.expect("exist");
+    assert_eq!(collider_groups.len(), 1);
+    assert_eq!(collider_groups[0].node.map(|node| node.value()), Some(0));
+    let colliders = collider_groups[0].colliders.as_deref().expect("exist");
+    assert_eq!(colliders.len(), 1);
+    let offset = colliders[0].offset.as_ref().expect("exist");
+    assert_eq!((offset.x, colliders[0].radius), (Some(-0.1), Some(0.1)));
+
+    let unsupported: Vec<_> = report
+        .unsupported
+        .iter()
+        .map(|unsupported| (unsupported.feature, unsupported.pointer.as_str()))
+        .collect();
+    assert_eq!(
+        unsupported,
+        [
+            (
+                UnsupportedFeature::InvalidColliderShape,
+                "/extensions/VRMC_springBone/colliders/0/shape"
+            ),
+            (
+                UnsupportedFeature::InvalidColliderShape,
+                "/extensions/VRMC_springBone/colliders/1/shape"
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_downgrade_mtoon() {
+    let gltf = gltf::Gltf::from_slice(
+        br#"{
+            "asset": { "version": "2.0" },
+            "materials": [
+                {
+                    "name": "Hair",
+                    "alphaMode": "BLEND",
+                    "doubleSided": true,
+                    "pbrMetallicRoughness": { "baseColorFactor": [1, 0.214, 0, 0.8] },
+                    "emissiveFactor": [0.5, 0.25, 0]
+                },
+                { "name": "Skin" }
+            ],
+            "images": [{ "uri": "hair.png" }],
+            "textures": [{ "source": 0 }, { "source": 0 }]
+        }"#,
+    )
+    .expect("ok");
+    let mtoon: vrm_spec::vrmc_materials_mtoon_1_0::VrmcMaterialsMtoonSchema =
+        serde_json::from_value(serde_json::json!({
+            "specVersion": "1.0",
+            "transparentWithZWrite": true,
+            "renderQueueOffsetNumber": 4,
+            "shadingToonyFactor": 0.625,
+            "shadingShiftFactor": 0.125,
+            "shadingShiftTexture": { "index": 1 },
+            "giEqualizationFactor": 0.75,
+            "outlineWidthMode": "worldCoordinates",
+            "outlineWidthFactor": 0.005,
+            "outlineLightingMixFactor": 0.75,
+            "uvAnimationScrollYSpeedFactor": -0.5,
+            "matcapTexture": { "index": 0, "texCoord": 1 }
+        }))
+        .expect("ok");
+
+    let mut report = vrm_spec::downgrade::DowngradeReport::default();
+    let material_properties =
+        vrm_spec::downgrade::downgrade_materials(&[Some(mtoon), None], &gltf.document, &mut report);
+    assert_eq!(material_properties.len(), 2);
+    assert_eq!(
+        material_properties[1].shader.as_deref(),
+        Some("VRM_USE_GLTFSHADER")
+    );
+
+    let material = &material_properties[0];
+    assert_eq!(material.shader.as_deref(), Some("VRM/MToon"));
+    assert_eq!(material.render_queue, Some(2505));
+    let float = |name: &str| material.float_properties.as_ref().expect("exist")[name];
+    assert_eq!(float("_BlendMode"), 3.0);
+    assert_eq!(float("_CullMode"), 0.0);
+    assert_eq!(float("_ShadeToony"), 0.5);
+    assert_eq!(float("_ShadeShift"), -0.5);
+    assert_eq!(float("_IndirectLightIntensity"), 0.25);
+    assert_eq!(float("_OutlineWidthMode"), 1.0);
+    assert_eq!(float("_OutlineWidth"), 0.5);
+    assert_eq!(float("_OutlineColorMode"), 1.0);
+    assert_eq!(float("_UvAnimScrollY"), 0.5);
+    let vector = |name: &str| &material.vector_properties.as_ref().expect("exist")[name];
+    for (value, expected) in vector("_Color").iter().zip([1.0, 0.5, 0.0, 0.8]) {
+        assert!((value - expected).abs() < 1e-3, "{}", value);
+    }
+    assert_eq!(vector("_EmissionColor"), &[0.5, 0.25, 0.0, 1.0]);
+    let keyword_map = material.keyword_map.as_ref().expect("exist");
+    assert!(keyword_map.contains_key("_ALPHABLEND_ON"));
+    assert!(keyword_map.contains_key("MTOON_OUTLINE_WIDTH_WORLD"));
+
+    // the migration restores the factors
+    let mut migration_report = migrate::MigrationReport::default();
+    let migrated = migrate::migrate_materials(&material_properties, &mut migration_report);
+    let mtoon = &migrated[0].as_ref().expect("exist").mtoon;
+    assert_eq!(mtoon.shading_toony_factor, Some(0.625));
+    assert_eq!(mtoon.shading_shift_factor, Some(0.125));
+    assert_eq!(mtoon.render_queue_offset_number, Some(4));
+
+    let pointers: Vec<&str> = report
+        .unsupported
+        .iter()
+        .map(|unsupported| unsupported.pointer.as_str())
+        .collect();
+    assert_eq!(
+        pointers,
+        [
+            "/materials/0/extensions/VRMC_materials_mtoon/shadingShiftTexture",
+            "/materials/0/extensions/VRMC_materials_mtoon/matcapTexture/texCoord"
+        ]
+    );
+}
+
+#[test]
+fn test_rotate_gltf() {
+    let gltf = gltf::Gltf::from_slice(
+        br#"{
+            "asset": { "version": "2.0" },
+            "accessors": [
+                {
+                    "bufferView": 0, "componentType": 5126, "count": 2, "type": "VEC3",
+                    "min": [-4, 2, -6], "max": [1, 5, 3]
+                },
+                { "bufferView": 1, "componentType": 5126, "count": 1, "type": "MAT4" },
+                {
+                    "bufferView": 2, "componentType": 5126, "count": 1, "type": "SCALAR",
+                    "min": [0], "max": [0]
+                },
+                { "bufferView": 3, "componentType": 5126, "count": 1, "type": "VEC4" }
+            ],
+            "animations": [
+                {
+                    "channels": [{ "sampler": 0, "target": { "node": 0, "path": "rotation" } }],
+                    "samplers": [{ "input": 2, "output": 3 }]
+                }
+            ],
+            "bufferViews": [
+                { "buffer": 0, "byteLength": 24 },
+                { "buffer": 0, "byteOffset": 24, "byteLength": 64 },
+                { "buffer": 0, "byteOffset": 88, "byteLength": 4 },
+                { "buffer": 0, "byteOffset": 92, "byteLength": 16 }
+            ],
+            "buffers": [{ "byteLength": 108 }],
+            "meshes": [{ "primitives": [{ "attributes": { "POSITION": 0 } }] }],
+            "nodes": [
+                {
+                    "mesh": 0, "skin": 0,
+                    "translation": [1, 2, 3], "rotation": [0.1, 0.2, 0.3, 0.9]
+                }
+            ],
+            "skins": [{ "inverseBindMatrices": 1, "joints": [0] }]
+        }"#,
+    )
+    .expect("ok");
+    let floats: [f32; 27] = [
+        1.0, 2.0, 3.0, -4.0, 5.0, -6.0, // positions
+        1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 1.0, 2.0, 3.0, 1.0, // IBM
+        0.0, // input
+        0.5, 0.5, 0.5, 0.5, // rotation
+    ];
+    let mut buffers = vec![floats
+        .iter()
+        .flat_map(|f| f.to_le_bytes())
+        .collect::<Vec<_>>()];
+    let mut root = gltf.document.into_json();
+    coordinate::rotate_gltf(&mut root, &mut buffers).expect("ok");
+
+    let floats: Vec<f32> = buffers[0]
+        .chunks_exact(4)
+        .map(|bytes| f32::from_le_bytes(bytes.try_into().expect("4 bytes")))
+        .collect();
+    assert_eq!(floats[..6], [-1.0, 2.0, -3.0, 4.0, 5.0, 6.0]);
+    assert_eq!(floats[6..10], [1.0, 0.0, 0.0, 0.0]);
+    assert_eq!(floats[18..22], [-1.0, 2.0, -3.0, 1.0]);
+    assert_eq!(floats[22], 0.0);
+    assert_eq!(floats[23..], [-0.5, 0.5, -0.5, 0.5]);
+    assert_eq!(root.accessors[0].min, Some(serde_json::json!([-1, 2, -3])));
+    assert_eq!(root.accessors[0].max, Some(serde_json::json!([4, 5, 6])));
+    assert_eq!(root.nodes[0].translation, Some([-1.0, 2.0, -3.0]));
+    assert_eq!(
+        root.nodes[0].rotation.map(|rotation| rotation.0),
+        Some([-0.1, 0.2, -0.3, 0.9])
+    );
+
+    // the rotation is its own inverse
+    coordinate::rotate_gltf(&mut root, &mut buffers).expect("ok");
+    assert_eq!(root.nodes[0].translation, Some([1.0, 2.0, 3.0]));
+    assert_eq!(root.accessors[0].min, Some(serde_json::json!([-4, 2, -6])));
+
+    buffers[0].truncate(100);
+    let err = coordinate::rotate_gltf(&mut root, &mut buffers).expect_err("out of the buffer");
+    assert!(matches!(
+        err,
+        coordinate::AccessorError { ref pointer, .. } if pointer == "/accessors/3"
+    ));
+}
+
+#[test]
+fn test_rotate_vrm1() {
+    let mut vrmc_vrm: vrmc_vrm_1_0::VRMCVrmSchema = serde_json::from_value(serde_json::json!({
+        "specVersion": "1.0",
+        "meta": { "name": "Sample", "authors": ["pixiv"], "licenseUrl": "https://vrm.dev/licenses/1.0/" },
+        "humanoid": { "humanBones": {} },
+        "lookAt": { "offsetFromHeadBone": [0.1, 0.06, 0.02] }
+    }))
+    .expect("ok");
+    coordinate::rotate_vrmc_vrm(&mut vrmc_vrm);
+    assert_eq!(
+        vrmc_vrm
+            .look_at
+            .and_then(|look_at| look_at.offset_from_head_bone),
+        Some(vec![-0.1, 0.06, -0.02])
+    );
+
+    let mut spring_bone: vrmc_spring_bone_1_0::VrmcSpringBoneSchema =
+        serde_json::from_value(serde_json::json!({
+            "specVersion": "1.0",
+            "colliders": [
+                {
+                    "node": 0,
+                    "shape": { "capsule": { "offset": [1, 2, 3], "radius": 0.1, "tail": [4, 5, 6] } },
+                    "extensions": {
+                        "VRMC_springBone_extended_collider": {
+                            "specVersion": "1.0",
+                            "shape": { "plane": { "offset": [1, 0, 1], "normal": [0, 0, 1] } }
+                        }
+                    }
+                }
+            ],
+            "springs": [{ "joints": [{ "node": 1, "gravityDir": [1, 0, 0] }] }]
+        }))
+        .expect("ok");
+    coordinate::rotate_spring_bone(&mut spring_bone).expect("ok");
+    let collider = &spring_bone.colliders.as_ref().expect("exist")[0];
+    let capsule = collider.shape.capsule.as_ref().expect("exist");
+    assert_eq!(capsule.offset, Some([-1.0, 2.0, -3.0]));
+    assert_eq!(capsule.tail, Some([-4.0, 5.0, -6.0]));
+    let plane = collider
+        .extended_collider()
+        .expect("exist")
+        .expect("ok")
+        .shape
+        .plane
+        .expect("exist");
+    assert_eq!(plane.offset, Some([-1.0, 0.0, -1.0]));
+    assert_eq!(plane.normal, Some([0.0, 0.0, -1.0]));
+    let joint = &spring_bone.springs.as_ref().expect("exist")[0].joints[0];
+    assert_eq!(joint.gravity_dir, Some([-1.0, 0.0, 0.0]));
+
+    let mut node_constraint: vrmc_node_constraint_1_0::VrmcNodeConstraintSchema =
+        serde_json::from_value(serde_json::json!({
+            "specVersion": "1.0",
+            "constraint": { "aim": { "source": 0, "aimAxis": "PositiveZ" } }
+        }))
+        .expect("ok");
+    coordinate::rotate_node_constraint(&mut node_constraint);
+    assert!(matches!(
+        node_constraint.constraint.aim.map(|aim| aim.aim_axis),
+        Some(vrmc_node_constraint_1_0::AimAxis::NegativeZ)
+    ));
+}
+
+#[test]
+fn test_validate_humanoid() {
+    use vrm_spec::validate::{validate_humanoid, HumanoidError};
+    use vrmc_vrm_1_0::HumanBoneName;
+
+    let mut humanoid: vrmc_vrm_1_0::Humanoid = serde_json::from_value(serde_json::json!({
+        "humanBones": {
+            "hips": { "node": 0 },
+            "spine": { "node": 1 },
+            "head": { "node": 2 },
+            "leftUpperLeg": { "node": 3 },
+            "leftLowerLeg": { "node": 4 },
+            "leftFoot": { "node": 5 },
+            "rightUpperLeg": { "node": 6 },
+            "rightLowerLeg": { "node": 7 },
+            "rightFoot": { "node": 8 },
+            "leftUpperArm": { "node": 9 },
+            "leftLowerArm": { "node": 10 },
+            "leftHand": { "node": 11 },
+            "rightUpperArm": { "node": 12 },
+            "rightLowerArm": { "node": 13 },
+            "rightHand": { "node": 14 }
+        }
+    }))
+    .expect("ok");
+    assert_eq!(validate_humanoid(&humanoid), []);
+
+    let extra: vrmc_vrm_1_0::HumanBones = serde_json::from_value(serde_json::json!({
+        "head": null,
+        "upperChest": { "node": 15 },
+        "leftThumbProximal": { "node": 16 },
+        "leftThumbMetacarpal": { "node": 17 },
+        "leftThumbDistal": {},
+        "rightIndexDistal": { "node": 14 }
+    }))
+    .expect("ok");
+    humanoid.human_bones.0.extend(extra.0);
+    let errors = validate_humanoid(&humanoid);
+    assert_eq!(
+        errors,
+        [
+            HumanoidError::MissingRequiredBone {
+                bone: HumanBoneName::Head
+            },
+            HumanoidError::MissingDependency {
+                bone: HumanBoneName::UpperChest,
+                dependency: HumanBoneName::Chest
+            },
+            HumanoidError::MissingDependency {
+                bone: HumanBoneName::RightIndexDistal,
+                dependency: HumanBoneName::RightIndexIntermediate
+            },
+            HumanoidError::MissingNode {
+                bone: HumanBoneName::LeftThumbDistal
+            },
+            HumanoidError::DuplicateNode {
+                bone: HumanBoneName::RightIndexDistal,
+                node: 14,
+                other: HumanBoneName::RightHand
+            },
+        ]
+    );
+    assert_eq!(
+        errors[4].to_string(),
+        "node 14 of bone 'rightIndexDistal' is also assigned to bone 'rightHand'"
+    );
+}
+
+#[test]
+fn test_validate_humanoid_hierarchy() {
+    use vrm_spec::validate::{validate_humanoid_hierarchy, validate_vrm0_humanoid_hierarchy};
+
+    let gltf = gltf::Gltf::from_slice(
+        br#"{
+            "asset": { "version": "2.0" },
+            "nodes": [
+                { "name": "Hips", "children": [1, 4] },
+                { "name": "Spine", "children": [2, 3] },
+                { "name": "Head" },
+                { "name": "LeftUpperArm" },
+                { "name": "LeftLowerArm" }
+            ]
+        }"#,
+    )
+    .expect("ok");
+
+    let humanoid: vrmc_vrm_1_0::Humanoid = serde_json::from_value(serde_json::json!({
+        "humanBones": {
+            "hips": { "node": 0 },
+            "spine": { "node": 1 },
+            "head": { "node": 2 },
+            "leftUpperArm": { "node": 3 },
+            "leftLowerArm": { "node": 4 },
+            "leftHand": { "node": 5 }
+        }
+    }))
+    .expect("ok");
+    let errors = validate_humanoid_hierarchy(&humanoid, &gltf.document);
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].bone, vrmc_vrm_1_0::HumanBoneName::LeftLowerArm);
+    assert_eq!(errors[0].parent, vrmc_vrm_1_0::HumanBoneName::LeftUpperArm);
+    assert_eq!(
+        errors[0].to_string(),
+        "node 4 'LeftLowerArm' of bone 'leftLowerArm' is not a descendant of \
+         node 3 'LeftUpperArm' of bone 'leftUpperArm'"
+    );
+
+    let humanoid: vrm_0_0::VRMHumanoid = serde_json::from_value(serde_json::json!({
+        "humanBones": [
+            { "bone": "hips", "node": 0 },
+            { "bone": "spine", "node": 1 },
+            { "bone": "head", "node": 2 },
+            { "bone": "leftUpperArm", "node": 3 },
+            { "bone": "leftLowerArm", "node": 3 },
+            { "bone": "leftThumbIntermediate", "node": 2 }
+        ]
+    }))
+    .expect("ok");
+    let errors: Vec<_> = validate_vrm0_humanoid_hierarchy(&humanoid, &gltf.document)
+        .into_iter()
+        .map(|error| (error.bone, error.parent))
+        .collect();
+    assert!(matches!(
+        errors[..],
+        [
+            (vrm_0_0::Bone::LeftLowerArm, vrm_0_0::Bone::LeftUpperArm),
+            (
+                vrm_0_0::Bone::LeftThumbIntermediate,
+                vrm_0_0::Bone::LeftLowerArm
+            )
+        ]
+    ));
+}
+
+#[test]
+fn test_validate_indices() {
+    use vrm_spec::validate::{validate_vrm0_indices, validate_vrm1_indices};
+
+    let gltf = gltf::Gltf::from_slice(
+        br#"{
+            "asset": { "version": "2.0" },
+            "nodes": [{ "name": "Hips" }, { "name": "Hair" }],
+            "materials": [{ "name": "Body" }]
+        }"#,
+    )
+    .expect("ok");
+
+    let vrm1 = vrm_spec::document::Vrm1Extensions {
+        vrmc_vrm: serde_json::from_value(serde_json::json!({
+            "specVersion": "1.0",
+            "meta": {
+                "name": "Sample",
+                "authors": ["VRM Consortium"],
+                "licenseUrl": "https://vrm.dev/licenses/1.0/",
+                "thumbnailImage": 0
+            },
+            "humanoid": { "humanBones": { "hips": { "node": 0 }, "spine": { "node": 2 } } },
+            "expressions": {
+                "preset": {
+                    "happy": { "morphTargetBinds": [{ "node": 1, "index": 0, "weight": 1.0 }] }
+                },
+                "custom": {
+                    "a/b": { "textureTransformBinds": [{ "material": 1 }] }
+                }
+            }
+        }))
+        .expect("ok"),
+        vrmc_spring_bone: Some(
+            serde_json::from_value(serde_json::json!({
+                "specVersion": "1.0",
+                "colliders": [{ "node": 0, "shape": { "sphere": { "radius": 0.1 } } }],
+                "colliderGroups": [{ "colliders": [0, 1] }],
+                "springs": [{ "joints": [{ "node": 1 }, { "node": 3 }], "colliderGroups": [0, -1] }]
+            }))
+            .expect("ok"),
+        ),
+        vrmc_materials_mtoon: vec![],
+        vrmc_node_constraints: vec![],
+    };
+    let errors: Vec<_> = validate_vrm1_indices(&vrm1, &gltf.document)
+        .into_iter()
+        .map(|error| (error.pointer, error.index, error.target))
+        .collect();
+    let error = |pointer: &str, index, target: &str| (pointer.to_owned(), index, target.to_owned());
+    assert_eq!(
+        errors,
+        [
+            error(
+                "/extensions/VRMC_vrm/humanoid/humanBones/spine/node",
+                2,
+                "/nodes"
+            ),
+            error("/extensions/VRMC_vrm/meta/thumbnailImage", 0, "/images"),
+            error(
+                "/extensions/VRMC_vrm/expressions/custom/a~1b/textureTransformBinds/0/material",
+                1,
+                "/materials"
+            ),
+            error(
+                "/extensions/VRMC_springBone/colliderGroups/0/colliders/1",
+                1,
+                "/extensions/VRMC_springBone/colliders"
+            ),
+            error(
+                "/extensions/VRMC_springBone/springs/0/joints/1/node",
+                3,
+                "/nodes"
+            ),
+            error(
+                "/extensions/VRMC_springBone/springs/0/colliderGroups/1",
+                -1,
+                "/extensions/VRMC_springBone/colliderGroups"
+            ),
+        ]
+    );
+
+    let vrm0: vrm_0_0::VRM0Schema = serde_json::from_value(serde_json::json!({
+        "humanoid": { "humanBones": [{ "bone": "hips", "node": 0 }] },
+        "blendShapeMaster": {
+            "blendShapeGroups": [{ "binds": [{ "mesh": 0, "index": 0, "weight": 100 }] }]
+        },
+        "secondaryAnimation": {
+            "boneGroups": [{ "bones": [1, 2], "colliderGroups": [0] }],
+            "colliderGroups": []
+        },
+        "materialProperties": [{ "floatProperties": {}, "textureProperties": { "_MainTex": 0 } }]
+    }))
+    .expect("ok");
+    let errors = validate_vrm0_indices(&vrm0, &gltf.document);
+    let messages: Vec<_> = errors.iter().map(ToString::to_string).collect();
+    assert_eq!(
+        messages,
+        [
+            "'/extensions/VRM/blendShapeMaster/blendShapeGroups/0/binds/0/mesh' refers to index 0 \
+             of '/meshes', which has 0 elements",
+            "'/extensions/VRM/secondaryAnimation/boneGroups/0/bones/1' refers to index 2 \
+             of '/nodes', which has 2 elements",
+            "'/extensions/VRM/secondaryAnimation/boneGroups/0/colliderGroups/0' refers to index 0 \
+             of '/extensions/VRM/secondaryAnimation/colliderGroups', which has 0 elements",
+            "'/extensions/VRM/materialProperties/0/textureProperties/_MainTex' refers to index 0 \
+             of '/textures', which has 0 elements",
+        ]
+    );
+}
+
+#[test]
+fn test_validate_morph_target_binds() {
+    use vrm_spec::validate::{
+        validate_morph_target_binds, validate_vrm0_morph_target_binds, MorphTargetBindErrorKind,
+    };
+
+    let gltf = gltf::Gltf::from_slice(
+        br#"{
+            "asset": { "version": "2.0" },
+            "buffers": [{ "byteLength": 36 }],
+            "bufferViews": [{ "buffer": 0, "byteLength": 36 }],
+            "accessors": [{
+                "bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3",
+                "min": [0, 0, 0], "max": [1, 1, 1]
+            }],
+            "meshes": [{
+                "primitives": [{
+                    "attributes": { "POSITION": 0 },
+                    "targets": [{ "POSITION": 0 }, { "POSITION": 0 }]
+                }]
+            }],
+            "nodes": [{ "name": "Face", "mesh": 0 }, { "name": "Head" }]
+        }"#,
+    )
+    .expect("ok");
+
+    let expressions: vrmc_vrm_1_0::Expressions = serde_json::from_value(serde_json::json!({
+        "preset": {
+            "happy": {
+                "morphTargetBinds": [
+                    { "node": 0, "index": 0, "weight": 1.0 },
+                    { "node": 0, "index": 2, "weight": 1.0 },
+                    { "node": 1, "index": 0, "weight": 1.0 },
+                    { "node": 0, "index": 0, "weight": 0.5 }
+                ]
+            }
+        },
+        "custom": {
+            "smile": { "morphTargetBinds": [{ "node": 0, "index": 1, "weight": 1.5 }] }
+        }
+    }))
+    .expect("ok");
+    let errors = validate_morph_target_binds(&expressions, &gltf.document);
+    let messages: Vec<_> = errors.iter().map(ToString::to_string).collect();
+    assert_eq!(
+        messages,
+        [
+            "expression 'happy': morph target 2 is out of range as mesh 0 has 2 morph targets",
+            "expression 'happy': node 1 has no mesh",
+            "expression 'happy': \
+             '/extensions/VRMC_vrm/expressions/preset/happy/morphTargetBinds/3' \
+             binds the same morph target as bind 0",
+            "expression 'smile': weight 1.5 is out of range 0..=1",
+        ]
+    );
+
+    let blend_shape: vrm_0_0::VRMBlendShape = serde_json::from_value(serde_json::json!({
+        "blendShapeGroups": [{
+            "name": "Joy",
+            "presetName": "joy",
+            "binds": [
+                { "mesh": 0, "index": 1, "weight": 100 },
+                { "mesh": 0, "index": -1, "weight": 100 },
+                { "mesh": 0, "index": 0, "weight": 120 }
+            ]
+        }]
+    }))
+    .expect("ok");
+    let errors = validate_vrm0_morph_target_binds(&blend_shape, &gltf.document);
+    assert_eq!(errors.len(), 2);
+    assert!(errors.iter().all(|error| error.expression == "Joy"));
+    assert_eq!(
+        errors[0].kind,
+        MorphTargetBindErrorKind::TargetOutOfRange {
+            mesh: 0,
+            index: -1,
+            count: 2
+        }
+    );
+    assert_eq!(
+        errors[1].pointer,
+        "/extensions/VRM/blendShapeMaster/blendShapeGroups/0/binds/2"
+    );
+    assert_eq!(
+        errors[1].kind,
+        MorphTargetBindErrorKind::WeightOutOfRange {
+            weight: 120.0,
+            max: 100.0
+        }
+    );
+}
+
+#[test]
+fn test_validate_spring_bone() {
+    use vrm_spec::validate::{validate_spring_bone, Severity};
+
+    let gltf = gltf::Gltf::from_slice(
+        br#"{
+            "asset": { "version": "2.0" },
+            "nodes": [
+                { "name": "Hair1", "children": [1] },
+                { "name": "Hair2", "children": [2] },
+                { "name": "Hair3" },
+                { "name": "Skirt" }
+            ]
+        }"#,
+    )
+    .expect("ok");
+
+    let spring_bone: vrmc_spring_bone_1_0::VrmcSpringBoneSchema =
+        serde_json::from_value(serde_json::json!({
+            "specVersion": "1.0",
+            "colliders": [{ "node": 0, "shape": { "sphere": { "radius": 0.1 } } }, { "node": 0, "shape": {} }],
+            "colliderGroups": [{ "colliders": [0] }],
+            "springs": [
+                {
+                    "joints": [
+                        { "node": 0, "dragForce": 1.5 },
+                        { "node": 1, "gravityDir": [0, -2, 0] },
+                        { "node": 2 }
+                    ],
+                    "colliderGroups": [0, 0, 1]
+                },
+                { "joints": [{ "node": 2 }, { "node": 3, "stiffness": -1 }] },
+                { "joints": [{ "node": 3 }] }
+            ]
+        }))
+        .expect("ok");
+    let issues: Vec<_> = validate_spring_bone(&spring_bone, &gltf.document)
+        .into_iter()
+        .map(|issue| (issue.severity(), issue.to_string(), issue.pointer))
+        .collect();
+    let pointer = |pointer: &str| format!("/extensions/VRMC_springBone/springs/{}", pointer);
+    assert_eq!(
+        issues,
+        [
+            (
+                Severity::Error,
+                "invalid collider shape: neither sphere nor capsule is given".to_owned(),
+                "/extensions/VRMC_springBone/colliders/1/shape".to_owned()
+            ),
+            (
+                Severity::Warning,
+                "dragForce 1.5 is out of range 0..=1".to_owned(),
+                pointer("0/joints/0/dragForce")
+            ),
+            (
+                Severity::Warning,
+                "gravityDir [0.0, -2.0, 0.0] is not a unit vector".to_owned(),
+                pointer("0/joints/1/gravityDir")
+            ),
+            (
+                Severity::Warning,
+                "collider group 0 is referenced twice".to_owned(),
+                pointer("0/colliderGroups/1")
+            ),
+            (
+                Severity::Error,
+                "collider group 1 does not exist".to_owned(),
+                pointer("0/colliderGroups/2")
+            ),
+            (
+                Severity::Error,
+                "node 2 is a joint of spring 0 as well".to_owned(),
+                pointer("1/joints/0/node")
+            ),
+            (
+                Severity::Error,
+                "node 3 is not a child of node 2 of the previous joint".to_owned(),
+                pointer("1/joints/1/node")
+            ),
+            (
+                Severity::Warning,
+                "stiffness -1 is less than 0".to_owned(),
+                pointer("1/joints/1/stiffness")
+            ),
+            (
+                Severity::Warning,
+                "the spring has 1 joints; 2 are needed to sway".to_owned(),
+                pointer("2/joints")
+            ),
+            (
+                Severity::Error,
+                "node 3 is a joint of spring 1 as well".to_owned(),
+                pointer("2/joints/0/node")
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_validation_report() {
+    use vrm_spec::validate::{validate_vrm1, Severity};
+
+    let gltf = gltf::Gltf::from_slice(
+        br#"{
+            "asset": { "version": "2.0" },
+            "nodes": [{ "name": "Hair1", "children": [1] }, { "name": "Hair2" }]
+        }"#,
+    )
+    .expect("ok");
+    let vrm1 = vrm_spec::document::Vrm1Extensions {
+        vrmc_vrm: serde_json::from_value(serde_json::json!({
+            "specVersion": "1.0",
+            "meta": {
+                "name": "Sample",
+                "authors": ["VRM Consortium"],
+                "licenseUrl": "https://vrm.dev/licenses/1.0/"
+            },
+            "humanoid": { "humanBones": {} }
+        }))
+        .expect("ok"),
+        vrmc_spring_bone: Some(
+            serde_json::from_value(serde_json::json!({
+                "specVersion": "1.0",
+                "springs": [{ "joints": [{ "node": 0, "hitRadius": -0.5 }, { "node": 1 }] }]
+            }))
+            .expect("ok"),
+        ),
+        vrmc_materials_mtoon: vec![],
+        vrmc_node_constraints: vec![],
+    };
+
+    let report = validate_vrm1(&vrm1, &gltf.document);
+    assert!(!report.is_valid());
+    assert_eq!(report.count(Severity::Error), 15);
+    assert_eq!(report.count(Severity::Warning), 1);
+
+    let value = serde_json::to_value(&report).expect("ok");
+    assert_eq!(value["numErrors"], 15);
+    assert_eq!(value["numWarnings"], 1);
+    assert_eq!(value["numInfos"], 0);
+    assert_eq!(
+        value["messages"][0],
+        serde_json::json!({
+            "code": "HUMANOID_MISSING_REQUIRED_BONE",
+            "severity": "error",
+            "pointer": "/extensions/VRMC_vrm/humanoid/humanBones/hips",
+            "message": "required bone 'hips' is missing"
+        })
+    );
+    assert_eq!(
+        value["messages"][15],
+        serde_json::json!({
+            "code": "SPRING_PARAMETER_OUT_OF_RANGE",
+            "severity": "warning",
+            "pointer": "/extensions/VRMC_springBone/springs/0/joints/0/hitRadius",
+            "message": "hitRadius -0.5 is less than 0"
+        })
+    );
+}
+
+#[test]
+fn test_validated_humanoid() {
+    use vrm_spec::validate::{HumanoidError, ValidatedHumanoid};
+    use vrmc_vrm_1_0::HumanBoneName;
+
+    let bones = [
+        "hips",
+        "spine",
+        "head",
+        "leftUpperLeg",
+        "leftLowerLeg",
+        "leftFoot",
+        "rightUpperLeg",
+        "rightLowerLeg",
+        "rightFoot",
+        "leftUpperArm",
+        "leftLowerArm",
+        "leftHand",
+        "rightUpperArm",
+        "rightLowerArm",
+        "rightHand",
+        "chest",
+    ];
+    let human_bones: serde_json::Map<_, _> = bones
+        .iter()
+        .enumerate()
+        .map(|(i, bone)| (bone.to_string(), serde_json::json!({ "node": i })))
+        .collect();
+    let humanoid: vrmc_vrm_1_0::Humanoid =
+        serde_json::from_value(serde_json::json!({ "humanBones": human_bones })).expect("ok");
+
+    let validated = ValidatedHumanoid::try_from(&humanoid).expect("valid");
+    assert_eq!(validated.hips(), 0);
+    assert_eq!(validated.right_hand(), 14);
+    assert_eq!(validated.get(HumanBoneName::Chest), Some(15));
+    assert_eq!(validated.get(HumanBoneName::Neck), None);
+    assert_eq!(validated.chest(), Some(15));
+    assert_eq!(validated.neck(), None);
+    assert_eq!(validated.right_little_distal(), None);
+    let order: Vec<_> = validated.iter().take(5).collect();
+    assert_eq!(
+        order,
+        [
+            (HumanBoneName::Hips, 0),
+            (HumanBoneName::Spine, 1),
+            (HumanBoneName::Chest, 15),
+            (HumanBoneName::Head, 2),
+            (HumanBoneName::LeftUpperLeg, 3),
+        ]
+    );
+    assert_eq!(validated.iter().count(), 16);
+
+    let mut humanoid = humanoid;
+    humanoid.human_bones.0.remove(&HumanBoneName::Hips);
+    assert_eq!(
+        ValidatedHumanoid::try_from(&humanoid),
+        Err(vec![HumanoidError::MissingRequiredBone {
+            bone: HumanBoneName::Hips
+        }])
+    );
+}
+
+#[test]
+fn test_human_bone_metadata() {
+    use vrm_0_0::Bone;
+    use vrmc_vrm_1_0::{HumanBoneGroup, HumanBoneName, HumanBoneSide};
+
+    let names: std::collections::HashSet<_> = HumanBoneName::ALL
+        .iter()
+        .map(|bone| serde_json::to_string(bone).expect("ok"))
+        .collect();
+    assert_eq!(names.len(), HumanBoneName::ALL.len());
+    for (i, bone) in HumanBoneName::ALL.iter().enumerate() {
+        if let Some(parent) = bone.parent() {
+            assert!(HumanBoneName::ALL[..i].contains(&parent), "{:?}", bone);
+        }
+        assert_eq!(bone.mirror().mirror(), *bone);
+        match bone.side() {
+            Some(side) => assert_ne!(bone.mirror().side(), Some(side)),
+            None => assert_eq!(bone.mirror(), *bone),
+        }
+    }
+    let required = HumanBoneName::ALL.iter().filter(|bone| bone.is_required());
+    assert_eq!(required.count(), 15);
+    assert_eq!(
+        HumanBoneName::LeftLowerArm.parent(),
+        Some(HumanBoneName::LeftUpperArm)
+    );
+    assert_eq!(
+        HumanBoneName::LeftThumbMetacarpal.group(),
+        HumanBoneGroup::LeftFingers
+    );
+    assert_eq!(HumanBoneName::LeftEye.group(), HumanBoneGroup::Head);
+    assert_eq!(HumanBoneName::LeftEye.side(), Some(HumanBoneSide::Left));
+    assert_eq!(HumanBoneName::RightToes.mirror(), HumanBoneName::LeftToes);
+    assert!(!HumanBoneName::Chest.is_required());
+
+    let names: std::collections::HashSet<_> = Bone::ALL
+        .iter()
+        .map(|bone| serde_json::to_string(bone).expect("ok"))
+        .collect();
+    assert_eq!(names.len(), Bone::ALL.len());
+    for (i, bone) in Bone::ALL.iter().enumerate() {
+        if let Some(parent) = bone.parent() {
+            assert!(Bone::ALL[..i].contains(&parent), "{:?}", bone);
+        }
+        assert_eq!(bone.mirror().mirror(), *bone);
+        match bone.side() {
+            Some(side) => assert_ne!(bone.mirror().side(), Some(side)),
+            None => assert_eq!(bone.mirror(), *bone),
+        }
+    }
+    let required = Bone::ALL.iter().filter(|bone| bone.is_required());
+    assert_eq!(required.count(), 17);
+    assert!(Bone::Chest.is_required());
+    assert_eq!(Bone::LeftThumbProximal.parent(), Some(Bone::LeftHand));
+    assert_eq!(Bone::RightLittleDistal.mirror(), Bone::LeftLittleDistal);
+    assert_eq!(Bone::RightShoulder.group(), HumanBoneGroup::RightArm);
+}
+
+#[test]
+fn test_bone_conversion() {
+    use vrm_0_0::Bone;
+    use vrmc_vrm_1_0::HumanBoneName;
+
+    fn name<T: serde::Serialize>(value: &T) -> serde_json::Value {
+        serde_json::to_value(value).expect("ok")
+    }
+
+    let shifted = [
+        (Bone::LeftThumbProximal, HumanBoneName::LeftThumbMetacarpal),
+        (
+            Bone::LeftThumbIntermediate,
+            HumanBoneName::LeftThumbProximal,
+        ),
+        (
+            Bone::RightThumbProximal,
+            HumanBoneName::RightThumbMetacarpal,
+        ),
+        (
+            Bone::RightThumbIntermediate,
+            HumanBoneName::RightThumbProximal,
+        ),
+    ];
+    for bone in Bone::ALL {
+        let converted = HumanBoneName::from(bone);
+        assert_eq!(Bone::from(converted), bone);
+        match shifted.iter().find(|(b, _)| *b == bone) {
+            Some(&(_, expected)) => assert_eq!(converted, expected),
+            // the other bones have the same name
+            None => assert_eq!(name(&converted), name(&bone)),
+        }
+    }
+    for name in HumanBoneName::ALL {
+        assert_eq!(HumanBoneName::from(Bone::from(name)), name);
+    }
+    assert_eq!(
+        Bone::from(HumanBoneName::LeftThumbDistal),
+        Bone::LeftThumbDistal
+    );
+}
+
+#[test]
+fn test_expression_preset_conversion() {
+    use vrm_0_0::PresetName;
+    use vrmc_vrm_1_0::ExpressionPresetName;
+
+    let pairs: Vec<(PresetName, ExpressionPresetName)> = serde_json::from_str(
+        r#"[
+            ["a", "aa"], ["i", "ih"], ["u", "ou"], ["e", "ee"], ["o", "oh"],
+            ["joy", "happy"], ["angry", "angry"], ["sorrow", "sad"], ["fun", "relaxed"],
+            ["blink", "blink"], ["blink_l", "blinkLeft"], ["blink_r", "blinkRight"],
+            ["lookup", "lookUp"], ["lookdown", "lookDown"],
+            ["lookleft", "lookLeft"], ["lookright", "lookRight"], ["neutral", "neutral"]
+        ]"#,
+    )
+    .expect("ok");
+    for (vrm0, vrm1) in pairs {
+        assert_eq!(ExpressionPresetName::try_from(vrm0), Ok(vrm1));
+        assert_eq!(PresetName::try_from(vrm1), Ok(vrm0));
+    }
+
+    assert_eq!(
+        ExpressionPresetName::try_from(PresetName::Unknown),
+        Err(PresetName::Unknown)
+    );
+    assert_eq!(
+        PresetName::try_from(ExpressionPresetName::Surprised),
+        Err(ExpressionPresetName::Surprised)
+    );
+}
+
+#[test]
+fn test_expression_category() {
+    use vrmc_vrm_1_0::{ExpressionCategory, ExpressionPresetName};
+
+    let count = |category| ExpressionPresetName::in_category(category).count();
+    assert_eq!(count(ExpressionCategory::Emotion), 5);
+    assert_eq!(count(ExpressionCategory::LipSync), 5);
+    assert_eq!(count(ExpressionCategory::Blink), 3);
+    assert_eq!(count(ExpressionCategory::LookAt), 4);
+    assert_eq!(count(ExpressionCategory::Other), 1);
+
+    assert_eq!(
+        ExpressionPresetName::in_category(ExpressionCategory::Blink).collect::<Vec<_>>(),
+        [
+            ExpressionPresetName::Blink,
+            ExpressionPresetName::BlinkLeft,
+            ExpressionPresetName::BlinkRight,
+        ]
+    );
+    assert_eq!(
+        ExpressionPresetName::Surprised.category(),
+        ExpressionCategory::Emotion
+    );
+    assert_eq!(
+        ExpressionPresetName::Oh.category(),
+        ExpressionCategory::LipSync
+    );
+    assert_eq!(
+        ExpressionPresetName::LookLeft.category(),
+        ExpressionCategory::LookAt
+    );
+    assert_eq!(
+        ExpressionPresetName::Neutral.category(),
+        ExpressionCategory::Other
+    );
+
+    // every preset is listed once
+    for name in ExpressionPresetName::ALL {
+        let listed = ExpressionPresetName::ALL.iter().filter(|&&n| n == name);
+        assert_eq!(listed.count(), 1);
+    }
+}